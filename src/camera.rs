@@ -0,0 +1,169 @@
+//! The camera module of mcpi-rs
+//! camera modes and scripted camera paths
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mcpi_rs::prelude::*;
+//! use std::time::Duration;
+//!
+//! let mc = Minecraft::connect();
+//!
+//! // fly over the spawn in 10 seconds
+//! let mut path = CameraPath::new();
+//! path.add_keyframe(Duration::from_secs(0), (-50.0, 90.0, 0.0))
+//!     .add_keyframe(Duration::from_secs(5), (0.0, 110.0, 0.0))
+//!     .add_keyframe(Duration::from_secs(10), (50.0, 90.0, 0.0));
+//! path.play(mc.clone(), 20);
+//!
+//! mc.mc_set_camera_mode(CameraMode::Normal(None));
+//! ```
+use crate::minecraft::Minecraft;
+use crate::position::Vec3;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// the mode of the camera
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CameraMode {
+    /// first person view of an entity (the player if `None`)
+    Normal(Option<i32>),
+    /// a fixed camera, moved by `mc_set_camera_pos`
+    Fixed,
+    /// third person view following an entity (the player if `None`)
+    Follow(Option<i32>),
+}
+impl CameraMode {
+    /// get the command and the arguments which set the mode
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(CameraMode::Follow(Some(42)).command(), ("camera.mode.setFollow", Some(42)));
+    /// assert_eq!(CameraMode::Fixed.command(), ("camera.mode.setFixed", None));
+    /// ```
+    pub fn command(self) -> (&'static str, Option<i32>) {
+        match self {
+            CameraMode::Normal(id) => ("camera.mode.setNormal", id),
+            CameraMode::Fixed => ("camera.mode.setFixed", None),
+            CameraMode::Follow(id) => ("camera.mode.setFollow", id),
+        }
+    }
+}
+/// how a camera path moves between two keyframes
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Interpolation {
+    /// a straight line at constant speed
+    Linear,
+    /// a Catmull-Rom spline through the keyframes, without sharp turns
+    Smooth,
+}
+/// a scripted camera path through timed keyframes
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CameraPath {
+    keyframes: Vec<(Duration, Vec3)>,
+    interpolation: Interpolation,
+}
+impl Default for CameraPath {
+    fn default() -> CameraPath {
+        CameraPath::new()
+    }
+}
+impl CameraPath {
+    /// construct an empty path with linear interpolation
+    pub fn new() -> CameraPath {
+        CameraPath {
+            keyframes: Vec::new(),
+            interpolation: Interpolation::Linear,
+        }
+    }
+    /// set how the path moves between two keyframes
+    pub fn set_interpolation(&mut self, interpolation: Interpolation) -> &mut CameraPath {
+        self.interpolation = interpolation;
+        self
+    }
+    /// add a keyframe: the camera is at `pos` when `time` has elapsed since the start
+    pub fn add_keyframe(&mut self, time: Duration, pos: impl Into<Vec3>) -> &mut CameraPath {
+        let index = self.keyframes.partition_point(|(t, _)| *t <= time);
+        self.keyframes.insert(index, (time, pos.into()));
+        self
+    }
+    /// get the time of the last keyframe
+    pub fn duration(&self) -> Duration {
+        self.keyframes
+            .last()
+            .map_or(Duration::from_secs(0), |k| k.0)
+    }
+    /// get the position of the camera when `time` has elapsed since the start
+    /// (`None` if the path has no keyframes)
+    ///
+    /// the position is clamped to the first and the last keyframe
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// let mut path = CameraPath::new();
+    /// path.add_keyframe(Duration::from_secs(0), (0.0, 0.0, 0.0))
+    ///     .add_keyframe(Duration::from_secs(4), (8.0, 4.0, 0.0));
+    ///
+    /// assert_eq!(path.position_at(Duration::from_secs(1)), Some(Vec3::new(2.0, 1.0, 0.0)));
+    /// assert_eq!(path.position_at(Duration::from_secs(9)), Some(Vec3::new(8.0, 4.0, 0.0)));
+    /// ```
+    pub fn position_at(&self, time: Duration) -> Option<Vec3> {
+        let first = self.keyframes.first()?;
+        let last = self.keyframes.last()?;
+        if time <= first.0 {
+            return Some(first.1);
+        }
+        if time >= last.0 {
+            return Some(last.1);
+        }
+        let i = self.keyframes.partition_point(|(t, _)| *t <= time) - 1;
+        let (t1, p1) = self.keyframes[i];
+        let (t2, p2) = self.keyframes[i + 1];
+        let t = (time - t1).as_secs_f64() / (t2 - t1).as_secs_f64();
+        Some(match self.interpolation {
+            Interpolation::Linear => p1.lerp(p2, t),
+            Interpolation::Smooth => {
+                let p0 = if i == 0 { p1 } else { self.keyframes[i - 1].1 };
+                let p3 = self.keyframes.get(i + 2).map_or(p2, |k| k.1);
+                Vec3::new(
+                    catmull_rom(p0.x, p1.x, p2.x, p3.x, t),
+                    catmull_rom(p0.y, p1.y, p2.y, p3.y, t),
+                    catmull_rom(p0.z, p1.z, p2.z, p3.z, t),
+                )
+            }
+        })
+    }
+    /// fix the camera and move it along the path, `fps` times per second
+    ///
+    /// this blocks until the path ends; the camera stays fixed at the last keyframe
+    pub fn play(&self, mc: Minecraft, fps: u32) {
+        if self.keyframes.is_empty() {
+            return;
+        }
+        let frame = Duration::from_secs(1) / fps.max(1);
+        mc.clone().mc_set_camera_mode(CameraMode::Fixed);
+        let start = Instant::now();
+        loop {
+            let elapsed = start.elapsed();
+            let pos = self.position_at(elapsed).expect("Path has keyframes");
+            mc.clone().mc_set_camera_pos(pos);
+            if elapsed >= self.duration() {
+                break;
+            }
+            let next = frame * (elapsed.as_nanos() / frame.as_nanos() + 1) as u32;
+            thread::sleep(next.saturating_sub(start.elapsed()));
+        }
+    }
+}
+fn catmull_rom(p0: f64, p1: f64, p2: f64, p3: f64, t: f64) -> f64 {
+    0.5 * (2.0 * p1
+        + (p2 - p0) * t
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t * t
+        + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t * t * t)
+}
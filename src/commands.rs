@@ -0,0 +1,369 @@
+//! The commands module of mcpi-rs
+//! turn chat messages like `!build tower 10 stone` into calls of registered handlers
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mcpi_rs::prelude::*;
+//! use std::time::Duration;
+//!
+//! let mc = Minecraft::connect();
+//!
+//! let mut commands = Commands::new();
+//! commands.register(
+//!     Command::new("tower", |mc, player, args| {
+//!         let height = args.next_int()?;
+//!         let item = args.next_item()?;
+//!         let pos = mc.clone().mc_get_pos_entity_int(player);
+//!         mc.mc_set_blocks(
+//!             pos.offset(2, 0, 0),
+//!             pos.offset(2, height - 1, 0),
+//!             Block::from_item(item),
+//!         );
+//!         Ok(())
+//!     })
+//!     .usage("<height> <block>")
+//!     .description("build a tower next to you")
+//!     .cooldown(Duration::from_secs(5)),
+//! );
+//!
+//! // poll the chat by hand
+//! loop {
+//!     commands.poll(mc.clone());
+//!     std::thread::sleep(Duration::from_millis(100));
+//! }
+//! ```
+use crate::event_loop::{EventFilter, EventLoop};
+use crate::events::ChatEvent;
+use crate::items::Item;
+use crate::minecraft::Minecraft;
+use crate::position::BlockPos;
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_PREFIX: &str = "!";
+
+/// the arguments of a command, parsed on demand
+///
+/// every `next_*` method consumes the words it parses and returns a message
+/// suitable for the chat if they are missing or malformed
+///
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let mut args = Args::new("tower 10 stone 1,2,3 -4 5 6");
+/// assert_eq!(args.next_str().unwrap(), "tower");
+/// assert_eq!(args.next_int().unwrap(), 10);
+/// assert!(args.next_item().unwrap() == STONE);
+/// assert_eq!(args.next_pos().unwrap(), BlockPos::new(1, 2, 3));
+/// assert_eq!(args.next_pos().unwrap(), BlockPos::new(-4, 5, 6));
+/// assert!(args.next_int().is_err());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Args {
+    words: Vec<String>,
+    index: usize,
+}
+impl Args {
+    /// split the arguments on whitespace
+    pub fn new(args: &str) -> Args {
+        Args {
+            words: args.split_whitespace().map(String::from).collect(),
+            index: 0,
+        }
+    }
+    /// how many words have not been consumed
+    pub fn remaining(&self) -> usize {
+        self.words.len() - self.index
+    }
+    /// whether every word has been consumed
+    pub fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+    /// consume the next word
+    pub fn next_str(&mut self) -> Result<String, String> {
+        let word = self
+            .words
+            .get(self.index)
+            .cloned()
+            .ok_or_else(|| String::from("missing argument"))?;
+        self.index += 1;
+        Ok(word)
+    }
+    /// consume the next word as an integer
+    pub fn next_int(&mut self) -> Result<i32, String> {
+        let word = self.next_str()?;
+        word.parse()
+            .map_err(|_| format!("`{}` is not an integer", word))
+    }
+    /// consume a position written as `x y z` or `x,y,z`
+    pub fn next_pos(&mut self) -> Result<BlockPos, String> {
+        let word = self.next_str()?;
+        if word.contains(',') {
+            let vec = word.split(',').collect::<Vec<&str>>();
+            let parse = |s: &str| {
+                s.trim()
+                    .parse::<i32>()
+                    .map_err(|_| format!("`{}` is not a position", word))
+            };
+            if vec.len() != 3 {
+                return Err(format!("`{}` is not a position", word));
+            }
+            Ok(BlockPos::new(
+                parse(vec[0])?,
+                parse(vec[1])?,
+                parse(vec[2])?,
+            ))
+        } else {
+            self.index -= 1;
+            Ok(BlockPos::new(
+                self.next_int()?,
+                self.next_int()?,
+                self.next_int()?,
+            ))
+        }
+    }
+    /// consume the next word as an item, a name (such as `stone`) or an id, like `str::parse`
+    pub fn next_item(&mut self) -> Result<Item, String> {
+        let word = self.next_str()?;
+        Item::from_name(&word).ok_or_else(|| format!("unknown block `{}`", word))
+    }
+    /// consume every remaining word, joined by spaces
+    pub fn rest(&mut self) -> String {
+        let rest = self.words[self.index..].join(" ");
+        self.index = self.words.len();
+        rest
+    }
+}
+type CommandHandler = Box<dyn FnMut(Minecraft, i32, &mut Args) -> Result<(), String> + Send>;
+/// a chat command
+///
+/// the handler receives the connection, the entity id of the player who sent the command
+/// and the arguments; an `Err` is posted to the chat together with the usage
+pub struct Command {
+    name: String,
+    usage: String,
+    description: String,
+    cooldown: Duration,
+    handler: CommandHandler,
+}
+impl Command {
+    /// construct a command (the name does not include the prefix)
+    pub fn new(
+        name: impl ToString,
+        handler: impl FnMut(Minecraft, i32, &mut Args) -> Result<(), String> + Send + 'static,
+    ) -> Command {
+        Command {
+            name: name.to_string().to_lowercase(),
+            usage: String::new(),
+            description: String::new(),
+            cooldown: Duration::from_secs(0),
+            handler: Box::new(handler),
+        }
+    }
+    /// set the arguments shown by `!help` (such as `<height> <block>`)
+    pub fn usage(mut self, usage: impl ToString) -> Command {
+        self.usage = usage.to_string();
+        self
+    }
+    /// set the description shown by `!help`
+    pub fn description(mut self, description: impl ToString) -> Command {
+        self.description = description.to_string();
+        self
+    }
+    /// set how long a player has to wait before using the command again after a successful use
+    pub fn cooldown(mut self, cooldown: Duration) -> Command {
+        self.cooldown = cooldown;
+        self
+    }
+    /// get the name of the command
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+/// the registered chat commands
+pub struct Commands {
+    prefix: String,
+    commands: BTreeMap<String, Command>,
+    last_used: HashMap<(i32, String), Instant>,
+}
+impl Default for Commands {
+    fn default() -> Commands {
+        Commands::new()
+    }
+}
+impl Commands {
+    /// construct an empty command set with the `DEFAULT_PREFIX`
+    pub fn new() -> Commands {
+        Commands::with_prefix(DEFAULT_PREFIX)
+    }
+    /// construct an empty command set with a custom prefix
+    pub fn with_prefix(prefix: impl ToString) -> Commands {
+        Commands {
+            prefix: prefix.to_string(),
+            commands: BTreeMap::new(),
+            last_used: HashMap::new(),
+        }
+    }
+    /// register a command (replacing the command with the same name)
+    ///
+    /// `help` is reserved for the generated help
+    pub fn register(&mut self, command: Command) -> &mut Commands {
+        assert_ne!(command.name, "help", "`help` is a reserved command");
+        self.commands.insert(command.name.clone(), command);
+        self
+    }
+    /// split a chat message into a command name and its arguments
+    /// (`None` if the message does not start with the prefix)
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let commands = Commands::new();
+    /// let (name, mut args) = commands.parse("!Build tower 10").unwrap();
+    /// assert_eq!(name, "build");
+    /// assert_eq!(args.rest(), "tower 10");
+    ///
+    /// assert!(commands.parse("hello").is_none());
+    /// ```
+    pub fn parse(&self, message: &str) -> Option<(String, Args)> {
+        let message = message.trim().strip_prefix(self.prefix.as_str())?;
+        let (name, args) = message
+            .split_once(char::is_whitespace)
+            .unwrap_or((message, ""));
+        if name.is_empty() {
+            return None;
+        }
+        Some((name.to_lowercase(), Args::new(args)))
+    }
+    /// get the help text, one line per command
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mut commands = Commands::new();
+    /// commands.register(Command::new("tp", |_, _, _| Ok(())).usage("<x y z>").description("teleport"));
+    /// assert_eq!(commands.help(), vec!["!help [command] - list the commands", "!tp <x y z> - teleport"]);
+    /// ```
+    pub fn help(&self) -> Vec<String> {
+        let mut lines = vec![format!("{}help [command] - list the commands", self.prefix)];
+        lines.extend(self.commands.values().map(|c| self.describe(c)));
+        lines
+    }
+    fn describe(&self, command: &Command) -> String {
+        let mut line = format!("{}{}", self.prefix, command.name);
+        if !command.usage.is_empty() {
+            line.push(' ');
+            line.push_str(&command.usage);
+        }
+        if !command.description.is_empty() {
+            line.push_str(" - ");
+            line.push_str(&command.description);
+        }
+        line
+    }
+    /// run the command in a chat post, return whether the post was a command
+    ///
+    /// replies (help, errors, cooldowns) are posted to the chat
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    /// # let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    /// # let mc = Minecraft::connect_to(listener.local_addr().unwrap());
+    ///
+    /// let pings = Arc::new(AtomicUsize::new(0));
+    /// let counter = pings.clone();
+    /// let mut commands = Commands::new();
+    /// commands.register(
+    ///     Command::new("ping", move |_, _, _| {
+    ///         counter.fetch_add(1, Ordering::SeqCst);
+    ///         Ok(())
+    ///     })
+    ///     .cooldown(Duration::from_secs(60)),
+    /// );
+    ///
+    /// assert!(commands.handle(mc.clone(), &ChatEvent::post(1, "!ping")));
+    /// // player 1 has to wait, player 2 does not
+    /// assert!(commands.handle(mc.clone(), &ChatEvent::post(1, "!ping")));
+    /// assert!(commands.handle(mc.clone(), &ChatEvent::post(2, "!ping")));
+    /// assert!(!commands.handle(mc, &ChatEvent::post(1, "ping")));
+    /// assert_eq!(pings.load(Ordering::SeqCst), 2);
+    /// ```
+    pub fn handle(&mut self, mc: Minecraft, post: &ChatEvent) -> bool {
+        let (name, mut args) = match self.parse(&post.message) {
+            Some(c) => c,
+            None => return false,
+        };
+        if name == "help" {
+            let lines = match args.next_str() {
+                Ok(n) => match self.commands.get(&n.to_lowercase()) {
+                    Some(c) => vec![self.describe(c)],
+                    None => vec![format!("unknown command {}{}", self.prefix, n)],
+                },
+                Err(_) => self.help(),
+            };
+            for line in lines {
+                mc.clone().mc_post_to_chat(line);
+            }
+            return true;
+        }
+        let prefix = self.prefix.clone();
+        let command = match self.commands.get_mut(&name) {
+            Some(c) => c,
+            None => {
+                mc.mc_post_to_chat(format!(
+                    "unknown command {}{}, try {}help",
+                    prefix, name, prefix
+                ));
+                return true;
+            }
+        };
+        let key = (post.entity_id, name);
+        let now = Instant::now();
+        if let Some(last) = self.last_used.get(&key) {
+            let elapsed = now.duration_since(*last);
+            if elapsed < command.cooldown {
+                // round up, so the last fraction of a second is still reported as 1s
+                let rem = command.cooldown - elapsed;
+                mc.mc_post_to_chat(format!(
+                    "{}{} is on cooldown for {}s",
+                    prefix,
+                    command.name,
+                    rem.as_secs() + u64::from(rem.subsec_nanos() > 0)
+                ));
+                return true;
+            }
+        }
+        // a failed invocation (bad arguments...) does not use up the cooldown
+        match (command.handler)(mc.clone(), post.entity_id, &mut args) {
+            Ok(()) => {
+                self.last_used.insert(key, now);
+            }
+            Err(e) => {
+                let usage = if command.usage.is_empty() {
+                    String::new()
+                } else {
+                    format!(" (usage: {}{} {})", prefix, command.name, command.usage)
+                };
+                mc.mc_post_to_chat(format!("{}{}: {}{}", prefix, command.name, e, usage));
+            }
+        }
+        true
+    }
+    /// poll `events.chat.posts` once and run the commands in it
+    pub fn poll(&mut self, mc: Minecraft) {
+        for post in mc.clone().mc_events_chat_posts() {
+            self.handle(mc.clone(), &post);
+        }
+    }
+    /// let an event loop run the commands in the chat posts
+    pub fn attach(mut self, event_loop: &mut EventLoop) {
+        event_loop.on_chat_post(EventFilter::new(), move |mc, post| {
+            self.handle(mc, post);
+        });
+    }
+}
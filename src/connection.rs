@@ -135,6 +135,23 @@ impl Connection {
         b.read_line(&mut s).expect("Failed to read line");
         s
     }
+    /// receive the replies of `count` commands sent before the first of them is read
+    ///
+    /// the replies are read with a single buffer, so none of them is lost when they arrive
+    /// together
+    pub(crate) fn receive_lines(self, count: usize) -> Vec<String> {
+        self.socket
+            .set_nonblocking(false)
+            .expect("Failed to set blocking mode");
+        let mut b = BufReader::new(self.socket.try_clone().unwrap());
+        (0..count)
+            .map(|_| {
+                let mut s = String::new();
+                b.read_line(&mut s).expect("Failed to read line");
+                s
+            })
+            .collect()
+    }
     /// close the connection
     /// ```
     /// use mcpi_rs::prelude::*;
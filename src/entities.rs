@@ -0,0 +1,478 @@
+//! The entities module of mcpi-rs
+//! the entity types which can be spawned by `world.spawnEntity`
+//!
+//! The ids are the legacy (pre-1.13) entity ids used by RaspberryJuice
+//!
+//! # Example
+//!
+//! ```
+//! use mcpi_rs::prelude::*;
+//!
+//! assert_eq!(EntityType::Zombie.id(), 54);
+//! assert_eq!(EntityType::from_id(54), Some(EntityType::Zombie));
+//! ```
+use self::EntityType::*;
+use crate::position::Vec3;
+
+/// the type of an entity
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EntityType {
+    DroppedItem,
+    ExperienceOrb,
+    AreaEffectCloud,
+    ElderGuardian,
+    WitherSkeleton,
+    Stray,
+    Egg,
+    LeashHitch,
+    Painting,
+    Arrow,
+    Snowball,
+    Fireball,
+    SmallFireball,
+    EnderPearl,
+    EnderSignal,
+    SplashPotion,
+    ThrownExpBottle,
+    ItemFrame,
+    WitherSkull,
+    PrimedTnt,
+    FallingBlock,
+    Firework,
+    Husk,
+    SpectralArrow,
+    ShulkerBullet,
+    DragonFireball,
+    ZombieVillager,
+    SkeletonHorse,
+    ZombieHorse,
+    ArmorStand,
+    Donkey,
+    Mule,
+    EvokerFangs,
+    Evoker,
+    Vex,
+    Vindicator,
+    Illusioner,
+    MinecartCommand,
+    Boat,
+    Minecart,
+    MinecartChest,
+    MinecartFurnace,
+    MinecartTnt,
+    MinecartHopper,
+    MinecartMobSpawner,
+    Creeper,
+    Skeleton,
+    Spider,
+    Giant,
+    Zombie,
+    Slime,
+    Ghast,
+    PigZombie,
+    Enderman,
+    CaveSpider,
+    Silverfish,
+    Blaze,
+    MagmaCube,
+    EnderDragon,
+    Wither,
+    Bat,
+    Witch,
+    Endermite,
+    Guardian,
+    Shulker,
+    Pig,
+    Sheep,
+    Cow,
+    Chicken,
+    Squid,
+    Wolf,
+    MushroomCow,
+    Snowman,
+    Ocelot,
+    IronGolem,
+    Horse,
+    Rabbit,
+    PolarBear,
+    Llama,
+    LlamaSpit,
+    Parrot,
+    Villager,
+    EnderCrystal,
+}
+/// every entity type, ordered by id
+pub const ENTITY_TYPES: [EntityType; 83] = [
+    DroppedItem,
+    ExperienceOrb,
+    AreaEffectCloud,
+    ElderGuardian,
+    WitherSkeleton,
+    Stray,
+    Egg,
+    LeashHitch,
+    Painting,
+    Arrow,
+    Snowball,
+    Fireball,
+    SmallFireball,
+    EnderPearl,
+    EnderSignal,
+    SplashPotion,
+    ThrownExpBottle,
+    ItemFrame,
+    WitherSkull,
+    PrimedTnt,
+    FallingBlock,
+    Firework,
+    Husk,
+    SpectralArrow,
+    ShulkerBullet,
+    DragonFireball,
+    ZombieVillager,
+    SkeletonHorse,
+    ZombieHorse,
+    ArmorStand,
+    Donkey,
+    Mule,
+    EvokerFangs,
+    Evoker,
+    Vex,
+    Vindicator,
+    Illusioner,
+    MinecartCommand,
+    Boat,
+    Minecart,
+    MinecartChest,
+    MinecartFurnace,
+    MinecartTnt,
+    MinecartHopper,
+    MinecartMobSpawner,
+    Creeper,
+    Skeleton,
+    Spider,
+    Giant,
+    Zombie,
+    Slime,
+    Ghast,
+    PigZombie,
+    Enderman,
+    CaveSpider,
+    Silverfish,
+    Blaze,
+    MagmaCube,
+    EnderDragon,
+    Wither,
+    Bat,
+    Witch,
+    Endermite,
+    Guardian,
+    Shulker,
+    Pig,
+    Sheep,
+    Cow,
+    Chicken,
+    Squid,
+    Wolf,
+    MushroomCow,
+    Snowman,
+    Ocelot,
+    IronGolem,
+    Horse,
+    Rabbit,
+    PolarBear,
+    Llama,
+    LlamaSpit,
+    Parrot,
+    Villager,
+    EnderCrystal,
+];
+impl EntityType {
+    /// get the legacy id of the entity type
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(EntityType::Minecart.id(), 42);
+    /// ```
+    pub fn id(self) -> i32 {
+        match self {
+            DroppedItem => 1,
+            ExperienceOrb => 2,
+            AreaEffectCloud => 3,
+            ElderGuardian => 4,
+            WitherSkeleton => 5,
+            Stray => 6,
+            Egg => 7,
+            LeashHitch => 8,
+            Painting => 9,
+            Arrow => 10,
+            Snowball => 11,
+            Fireball => 12,
+            SmallFireball => 13,
+            EnderPearl => 14,
+            EnderSignal => 15,
+            SplashPotion => 16,
+            ThrownExpBottle => 17,
+            ItemFrame => 18,
+            WitherSkull => 19,
+            PrimedTnt => 20,
+            FallingBlock => 21,
+            Firework => 22,
+            Husk => 23,
+            SpectralArrow => 24,
+            ShulkerBullet => 25,
+            DragonFireball => 26,
+            ZombieVillager => 27,
+            SkeletonHorse => 28,
+            ZombieHorse => 29,
+            ArmorStand => 30,
+            Donkey => 31,
+            Mule => 32,
+            EvokerFangs => 33,
+            Evoker => 34,
+            Vex => 35,
+            Vindicator => 36,
+            Illusioner => 37,
+            MinecartCommand => 40,
+            Boat => 41,
+            Minecart => 42,
+            MinecartChest => 43,
+            MinecartFurnace => 44,
+            MinecartTnt => 45,
+            MinecartHopper => 46,
+            MinecartMobSpawner => 47,
+            Creeper => 50,
+            Skeleton => 51,
+            Spider => 52,
+            Giant => 53,
+            Zombie => 54,
+            Slime => 55,
+            Ghast => 56,
+            PigZombie => 57,
+            Enderman => 58,
+            CaveSpider => 59,
+            Silverfish => 60,
+            Blaze => 61,
+            MagmaCube => 62,
+            EnderDragon => 63,
+            Wither => 64,
+            Bat => 65,
+            Witch => 66,
+            Endermite => 67,
+            Guardian => 68,
+            Shulker => 69,
+            Pig => 90,
+            Sheep => 91,
+            Cow => 92,
+            Chicken => 93,
+            Squid => 94,
+            Wolf => 95,
+            MushroomCow => 96,
+            Snowman => 97,
+            Ocelot => 98,
+            IronGolem => 99,
+            Horse => 100,
+            Rabbit => 101,
+            PolarBear => 102,
+            Llama => 103,
+            LlamaSpit => 104,
+            Parrot => 105,
+            Villager => 120,
+            EnderCrystal => 200,
+        }
+    }
+    /// get the entity type of a legacy id
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(EntityType::from_id(10), Some(EntityType::Arrow));
+    /// assert_eq!(EntityType::from_id(-1), None);
+    /// ```
+    pub fn from_id(id: i32) -> Option<EntityType> {
+        ENTITY_TYPES.iter().copied().find(|t| t.id() == id)
+    }
+    /// whether the entity type is a mob (a living entity with ai)
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert!(EntityType::Creeper.is_mob());
+    /// assert!(!EntityType::MinecartChest.is_mob());
+    /// ```
+    pub fn is_mob(self) -> bool {
+        matches!(
+            self,
+            ElderGuardian
+                | WitherSkeleton
+                | Stray
+                | Husk
+                | ZombieVillager
+                | SkeletonHorse
+                | ZombieHorse
+                | Donkey
+                | Mule
+                | Evoker
+                | Vex
+                | Vindicator
+                | Illusioner
+        ) || ((50..=120).contains(&self.id()) && self != LlamaSpit)
+    }
+    /// whether the entity type is a minecart
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert!(EntityType::MinecartHopper.is_minecart());
+    /// ```
+    pub fn is_minecart(self) -> bool {
+        matches!(
+            self,
+            MinecartCommand
+                | Minecart
+                | MinecartChest
+                | MinecartFurnace
+                | MinecartTnt
+                | MinecartHopper
+                | MinecartMobSpawner
+        )
+    }
+    /// whether the entity type is a projectile
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert!(EntityType::Arrow.is_projectile());
+    /// assert!(!EntityType::ItemFrame.is_projectile());
+    /// ```
+    pub fn is_projectile(self) -> bool {
+        matches!(
+            self,
+            Egg | Arrow
+                | Snowball
+                | Fireball
+                | SmallFireball
+                | EnderPearl
+                | SplashPotion
+                | ThrownExpBottle
+                | WitherSkull
+                | SpectralArrow
+                | ShulkerBullet
+                | DragonFireball
+                | LlamaSpit
+        )
+    }
+}
+/// an entity in the world
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Entity {
+    id: i32,
+    type_id: i32,
+    pos: Vec3,
+}
+impl Entity {
+    /// construct an entity
+    pub fn new(id: i32, type_id: i32, pos: impl Into<Vec3>) -> Entity {
+        Entity {
+            id,
+            type_id,
+            pos: pos.into(),
+        }
+    }
+    /// get the entity id
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+    /// get the type of the entity (`None` if the type is not in the catalog)
+    pub fn kind(&self) -> Option<EntityType> {
+        EntityType::from_id(self.type_id)
+    }
+    /// get the legacy type id of the entity
+    pub fn type_id(&self) -> i32 {
+        self.type_id
+    }
+    /// get the position of the entity when it was queried
+    pub fn pos(&self) -> Vec3 {
+        self.pos
+    }
+    /// convert the reply of `world.getEntities` to entities
+    ///
+    /// every entity is formatted as `id,typeId,typeName,x,y,z` and separated by `|`
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let v = Entity::decode_list("12,54,ZOMBIE,1.5,64.0,-3.5|13,90,PIG,0.0,70.0,0.0|\n");
+    /// assert_eq!(v.len(), 2);
+    /// assert_eq!(v[0].id(), 12);
+    /// assert_eq!(v[0].kind(), Some(EntityType::Zombie));
+    /// assert_eq!(v[1].pos(), Vec3::new(0.0, 70.0, 0.0));
+    /// ```
+    pub fn decode_list(s: impl ToString) -> Vec<Entity> {
+        s.to_string()
+            .trim()
+            .split('|')
+            .filter(|e| !e.is_empty())
+            .map(|e| {
+                let vec = e.split(',').collect::<Vec<&str>>();
+                Entity::new(
+                    vec[0].parse().expect("Failed to parse entity id"),
+                    vec[1].parse().expect("Failed to parse entity type"),
+                    Vec3::new(
+                        vec[3].parse().expect("Failed to parse x"),
+                        vec[4].parse().expect("Failed to parse y"),
+                        vec[5].parse().expect("Failed to parse z"),
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn ids_are_unique_and_round_trip() {
+        let ids = ENTITY_TYPES.iter().map(|t| t.id()).collect::<HashSet<_>>();
+        assert_eq!(ids.len(), ENTITY_TYPES.len());
+        for &t in ENTITY_TYPES.iter() {
+            assert_eq!(EntityType::from_id(t.id()), Some(t));
+        }
+        for id in [0, 38, 39, 48, 49, 70, 89, 106, 119, 199, 201] {
+            assert_eq!(EntityType::from_id(id), None, "{}", id);
+        }
+    }
+
+    #[test]
+    fn categories_are_disjoint() {
+        for &t in ENTITY_TYPES.iter() {
+            let n = [t.is_mob(), t.is_minecart(), t.is_projectile()]
+                .iter()
+                .filter(|&&b| b)
+                .count();
+            assert!(n <= 1, "{:?}", t);
+        }
+        assert!(!LlamaSpit.is_mob() && LlamaSpit.is_projectile());
+        assert!(Villager.is_mob() && !EnderCrystal.is_mob());
+    }
+
+    #[test]
+    fn decode_list() {
+        assert!(Entity::decode_list("").is_empty());
+        assert!(Entity::decode_list("\n").is_empty());
+        let v = Entity::decode_list("7,54,ZOMBIE,-1.5,64.0,2.25|8,9999,UNKNOWN,0.0,-3.0,0.0\n");
+        assert_eq!(v.len(), 2);
+        assert_eq!(v[0], Entity::new(7, 54, Vec3::new(-1.5, 64.0, 2.25)));
+        assert_eq!(v[0].kind(), Some(Zombie));
+        // a type outside the catalog keeps its id
+        assert_eq!(v[1].kind(), None);
+        assert_eq!(v[1].type_id(), 9999);
+        assert_eq!(v[1].pos(), Vec3::new(0.0, -3.0, 0.0));
+    }
+}
@@ -0,0 +1,39 @@
+//! The error module of mcpi-rs
+use crate::minecraft::Dialect;
+use std::fmt::{Display, Formatter};
+
+/// the errors reported by mcpi-rs (connection failures still panic)
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Error {
+    /// the command is not supported by the dialect of the server
+    Unsupported { command: String, dialect: Dialect },
+    /// a line of a sign is longer than the game can show
+    LineTooLong { line: usize, len: usize, max: usize },
+    /// a value is out of the range the game accepts
+    OutOfRange { what: String, value: i64, max: i64 },
+    /// a name that is not a known block
+    UnknownBlock { name: String },
+    /// a block state that is not `namespace:name[key=value,...]`
+    InvalidBlockState { state: String },
+}
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Unsupported { command, dialect } => {
+                write!(f, "`{}` is not supported by {}", command, dialect)
+            }
+            Error::LineTooLong { line, len, max } => write!(
+                f,
+                "line {} is {} characters long, the limit is {}",
+                line, len, max
+            ),
+            Error::OutOfRange { what, value, max } => {
+                write!(f, "{} {} is out of range 0..={}", what, value, max)
+            }
+            Error::UnknownBlock { name } => write!(f, "unknown block `{}`", name),
+            Error::InvalidBlockState { state } => write!(f, "invalid block state `{}`", state),
+        }
+    }
+}
+impl std::error::Error for Error {}
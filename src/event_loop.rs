@@ -0,0 +1,478 @@
+//! The event loop module of mcpi-rs
+//! poll the events on a background thread and dispatch them to handlers
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mcpi_rs::prelude::*;
+//! use std::time::Duration;
+//!
+//! let mut el = EventLoop::new(Minecraft::connect());
+//! el.set_interval(Duration::from_millis(50));
+//! el.on_block_hit(EventFilter::new().block(DIAMOND_BLOCK), |mc, hit| {
+//!     mc.mc_post_to_chat(format!("{} found a diamond block", hit.entity_id));
+//! });
+//! el.on_chat_post(EventFilter::new(), |mc, post| {
+//!     mc.mc_post_to_chat(format!("echo: {}", post.message));
+//! });
+//!
+//! let handle = el.spawn();
+//! // -- snip --
+//! handle.stop();
+//! ```
+use crate::events::{
+    BlockChangeEvent, BlockEvent, ChatEvent, Event, EventKind, MoveEvent, PlayerEvent,
+    ProjectileEvent,
+};
+use crate::items::Item;
+use crate::minecraft::Minecraft;
+use crate::position::BlockPos;
+use crate::region::Region;
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// something which produces events each time the event loop polls
+pub trait EventSource: Send {
+    fn poll(&mut self, mc: &Minecraft) -> Vec<Event>;
+}
+/// the events reported by the server (`events.*`)
+pub struct ServerEvents;
+impl EventSource for ServerEvents {
+    fn poll(&mut self, mc: &Minecraft) -> Vec<Event> {
+        mc.clone().mc_events_poll()
+    }
+}
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum PlayerFilter {
+    Id(i32),
+    Name(String),
+}
+/// decide which events are passed to a handler
+///
+/// an event passes the filter only if it passes every condition of it
+///
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// // the hits of player 7 inside the cuboid from (0,60,0) to (10,70,10)
+/// let filter = EventFilter::new().region((10, 70, 10), (0, 60, 0)).player(7);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventFilter {
+    region: Option<Region>,
+    player: Option<PlayerFilter>,
+    block: Option<Item>,
+}
+impl EventFilter {
+    /// construct a filter which passes every event
+    pub fn new() -> EventFilter {
+        EventFilter::default()
+    }
+    /// only pass the events inside the cuboid between two corners (inclusive)
+    ///
+    /// events without a position (such as chat posts) never pass
+    pub fn region(self, corner1: impl Into<BlockPos>, corner2: impl Into<BlockPos>) -> EventFilter {
+        self.within(Region::new(corner1, corner2))
+    }
+    /// only pass the events inside a region
+    ///
+    /// events without a position (such as chat posts) never pass
+    pub fn within(mut self, region: Region) -> EventFilter {
+        self.region = Some(region);
+        self
+    }
+    /// only pass the events caused by the player with the entity id
+    pub fn player(mut self, entity_id: i32) -> EventFilter {
+        self.player = Some(PlayerFilter::Id(entity_id));
+        self
+    }
+    /// only pass the events caused by the player with the name
+    pub fn player_name(mut self, name: impl ToString) -> EventFilter {
+        self.player = Some(PlayerFilter::Name(name.to_string()));
+        self
+    }
+    /// only pass the events at a block of the item (the block is read when the event is dispatched)
+    ///
+    /// events without a position (such as chat posts) never pass
+    pub fn block(mut self, item: Item) -> EventFilter {
+        self.block = Some(item);
+        self
+    }
+    /// whether the event passes the filter
+    ///
+    /// `mc` is only used when the filter needs to look up a block or a player
+    pub fn matches(&self, mc: &Minecraft, event: &Event) -> bool {
+        if let Some(region) = self.region {
+            if !event.pos().is_some_and(|p| region.contains(p)) {
+                return false;
+            }
+        }
+        if let Some(player) = &self.player {
+            let matched = match (player, event.entity_id(), event.player_name()) {
+                (PlayerFilter::Id(id), Some(e), _) => *id == e,
+                (PlayerFilter::Name(name), _, Some(n)) => name == n,
+                (PlayerFilter::Id(id), None, Some(n)) => {
+                    mc.clone().mc_get_player_entity_id(n) == Some(*id)
+                }
+                (PlayerFilter::Name(name), Some(e), None) => {
+                    mc.clone().mc_get_player_entity_id(name) == Some(e)
+                }
+                _ => false,
+            };
+            if !matched {
+                return false;
+            }
+        }
+        if let Some(item) = &self.block {
+            match event.pos() {
+                Some(p) => {
+                    if mc.clone().mc_get_block(p).item() != *item {
+                        return false;
+                    }
+                }
+                None => return false,
+            }
+        }
+        true
+    }
+}
+/// a panic caught by the event loop, in an event source, a filter or a handler
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct LoopPanic {
+    /// the event being dispatched (`None` if an event source panicked while polling)
+    pub event: Option<Event>,
+    /// the message of the panic
+    pub message: String,
+}
+impl Display for LoopPanic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.event {
+            Some(event) => write!(f, "event handler panicked on {}: {}", event, self.message),
+            None => write!(f, "event source panicked: {}", self.message),
+        }
+    }
+}
+type Handler = Box<dyn FnMut(Minecraft, &Event) + Send>;
+type PanicHandler = Box<dyn FnMut(Minecraft, &LoopPanic) + Send>;
+struct Registration {
+    kind: Option<EventKind>,
+    filter: EventFilter,
+    handler: Handler,
+}
+/// poll the event sources and dispatch the events to the registered handlers
+pub struct EventLoop {
+    mc: Minecraft,
+    interval: Duration,
+    sources: Vec<Box<dyn EventSource>>,
+    handlers: Vec<Registration>,
+    panic_handler: Option<PanicHandler>,
+}
+impl EventLoop {
+    /// construct an event loop which polls the server events every `DEFAULT_INTERVAL`
+    pub fn new(mc: Minecraft) -> EventLoop {
+        EventLoop {
+            mc,
+            interval: DEFAULT_INTERVAL,
+            sources: vec![Box::new(ServerEvents)],
+            handlers: Vec::new(),
+            panic_handler: None,
+        }
+    }
+    /// set the time to wait between two polls
+    pub fn set_interval(&mut self, interval: Duration) -> &mut EventLoop {
+        self.interval = interval;
+        self
+    }
+    /// get the time to wait between two polls
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+    /// poll another event source each time the server events are polled
+    pub fn add_source(&mut self, source: impl EventSource + 'static) -> &mut EventLoop {
+        self.sources.push(Box::new(source));
+        self
+    }
+    /// register the handler of the panics caught by the event loop
+    ///
+    /// a panicking event source, filter or handler does not stop the event loop, the panic is
+    /// passed to this handler instead (and ignored without one); the panics of this handler
+    /// are ignored
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mut el = EventLoop::new(Minecraft::connect());
+    /// el.on_panic(|mc, panic| mc.mc_post_to_chat(format!("oops: {}", panic)));
+    /// ```
+    pub fn on_panic(
+        &mut self,
+        handler: impl FnMut(Minecraft, &LoopPanic) + Send + 'static,
+    ) -> &mut EventLoop {
+        self.panic_handler = Some(Box::new(handler));
+        self
+    }
+    /// register a handler for the events of a kind
+    pub fn on(
+        &mut self,
+        kind: EventKind,
+        filter: EventFilter,
+        handler: impl FnMut(Minecraft, &Event) + Send + 'static,
+    ) -> &mut EventLoop {
+        self.handlers.push(Registration {
+            kind: Some(kind),
+            filter,
+            handler: Box::new(handler),
+        });
+        self
+    }
+    /// register a handler for the events of every kind
+    pub fn on_any(
+        &mut self,
+        filter: EventFilter,
+        handler: impl FnMut(Minecraft, &Event) + Send + 'static,
+    ) -> &mut EventLoop {
+        self.handlers.push(Registration {
+            kind: None,
+            filter,
+            handler: Box::new(handler),
+        });
+        self
+    }
+    /// register a handler for the block hits
+    pub fn on_block_hit(
+        &mut self,
+        filter: EventFilter,
+        mut handler: impl FnMut(Minecraft, &BlockEvent) + Send + 'static,
+    ) -> &mut EventLoop {
+        self.on(EventKind::BlockHit, filter, move |mc, event| {
+            if let Event::BlockHit(e) = event {
+                handler(mc, e)
+            }
+        })
+    }
+    /// register a handler for the chat posts
+    pub fn on_chat_post(
+        &mut self,
+        filter: EventFilter,
+        mut handler: impl FnMut(Minecraft, &ChatEvent) + Send + 'static,
+    ) -> &mut EventLoop {
+        self.on(EventKind::ChatPost, filter, move |mc, event| {
+            if let Event::ChatPost(e) = event {
+                handler(mc, e)
+            }
+        })
+    }
+    /// register a handler for the projectile hits
+    pub fn on_projectile_hit(
+        &mut self,
+        filter: EventFilter,
+        mut handler: impl FnMut(Minecraft, &ProjectileEvent) + Send + 'static,
+    ) -> &mut EventLoop {
+        self.on(EventKind::ProjectileHit, filter, move |mc, event| {
+            if let Event::ProjectileHit(e) = event {
+                handler(mc, e)
+            }
+        })
+    }
+    /// register a handler for the players joining (needs a `PlayerWatcher` source)
+    pub fn on_player_joined(
+        &mut self,
+        filter: EventFilter,
+        mut handler: impl FnMut(Minecraft, &PlayerEvent) + Send + 'static,
+    ) -> &mut EventLoop {
+        self.on(EventKind::PlayerJoined, filter, move |mc, event| {
+            if let Event::PlayerJoined(e) = event {
+                handler(mc, e)
+            }
+        })
+    }
+    /// register a handler for the players leaving (needs a `PlayerWatcher` source)
+    pub fn on_player_left(
+        &mut self,
+        filter: EventFilter,
+        mut handler: impl FnMut(Minecraft, &PlayerEvent) + Send + 'static,
+    ) -> &mut EventLoop {
+        self.on(EventKind::PlayerLeft, filter, move |mc, event| {
+            if let Event::PlayerLeft(e) = event {
+                handler(mc, e)
+            }
+        })
+    }
+    /// register a handler for the players moving to another tile (needs a `PlayerWatcher` source)
+    pub fn on_player_moved(
+        &mut self,
+        filter: EventFilter,
+        mut handler: impl FnMut(Minecraft, &MoveEvent) + Send + 'static,
+    ) -> &mut EventLoop {
+        self.on(EventKind::PlayerMovedTile, filter, move |mc, event| {
+            if let Event::PlayerMovedTile(e) = event {
+                handler(mc, e)
+            }
+        })
+    }
+    /// register a handler for the block changes (needs a `RegionWatcher` source)
+    pub fn on_block_changed(
+        &mut self,
+        filter: EventFilter,
+        mut handler: impl FnMut(Minecraft, &BlockChangeEvent) + Send + 'static,
+    ) -> &mut EventLoop {
+        self.on(EventKind::BlockChanged, filter, move |mc, event| {
+            if let Event::BlockChanged(e) = event {
+                handler(mc, e)
+            }
+        })
+    }
+    /// pass an event to every handler it matches
+    ///
+    /// a panicking filter or handler is passed to the `on_panic` handler and does not stop the
+    /// others, return how many of them panicked
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// # let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    /// # let mc = Minecraft::connect_to(listener.local_addr().unwrap());
+    ///
+    /// let mut el = EventLoop::new(mc);
+    /// let hits = Arc::new(AtomicUsize::new(0));
+    /// let counter = hits.clone();
+    /// el.on_block_hit(EventFilter::new().player(7), move |_, _| {
+    ///     counter.fetch_add(1, Ordering::SeqCst);
+    /// });
+    /// el.on_any(EventFilter::new(), |_, _| panic!("oops"));
+    /// let panics = Arc::new(AtomicUsize::new(0));
+    /// let counter = panics.clone();
+    /// el.on_panic(move |_, panic| {
+    ///     assert_eq!(panic.message, "oops");
+    ///     counter.fetch_add(1, Ordering::SeqCst);
+    /// });
+    ///
+    /// assert_eq!(el.dispatch(&Event::BlockHit(BlockEvent::hit((0, 0, 0), BlockFace::Up, 7))), 1);
+    /// el.dispatch(&Event::BlockHit(BlockEvent::hit((0, 0, 0), BlockFace::Up, 8)));
+    /// assert_eq!(hits.load(Ordering::SeqCst), 1);
+    /// assert_eq!(panics.load(Ordering::SeqCst), 2);
+    /// ```
+    pub fn dispatch(&mut self, event: &Event) -> usize {
+        let mut panicked = 0;
+        for r in self.handlers.iter_mut() {
+            if r.kind.is_some_and(|k| k != event.kind()) {
+                continue;
+            }
+            let (mc, filter, handler) = (&self.mc, &r.filter, &mut r.handler);
+            // the filter may read the world, so a bad reply can panic it as well
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                if filter.matches(mc, event) {
+                    handler(mc.clone(), event)
+                }
+            }));
+            if let Err(payload) = result {
+                report(&mut self.panic_handler, mc, Some(event), payload);
+                panicked += 1;
+            }
+        }
+        panicked
+    }
+    /// poll every source once and dispatch the events, return how many events were polled
+    ///
+    /// a panicking source is passed to the `on_panic` handler and polls no events
+    pub fn poll_once(&mut self) -> usize {
+        let mut events = Vec::new();
+        for source in self.sources.iter_mut() {
+            let mc = &self.mc;
+            match catch_unwind(AssertUnwindSafe(|| source.poll(mc))) {
+                Ok(polled) => events.extend(polled),
+                Err(payload) => report(&mut self.panic_handler, mc, None, payload),
+            }
+        }
+        for event in events.iter() {
+            self.dispatch(event);
+        }
+        events.len()
+    }
+    /// run the event loop on a new thread
+    pub fn spawn(mut self) -> EventLoopHandle {
+        let stop = Arc::new(AtomicBool::new(false));
+        let flag = stop.clone();
+        let thread = thread::spawn(move || {
+            while !flag.load(Ordering::SeqCst) {
+                self.poll_once();
+                thread::park_timeout(self.interval);
+            }
+            self
+        });
+        EventLoopHandle {
+            stop,
+            thread: Some(thread),
+        }
+    }
+}
+/// pass a caught panic to the panic handler
+fn report(
+    panic_handler: &mut Option<PanicHandler>,
+    mc: &Minecraft,
+    event: Option<&Event>,
+    payload: Box<dyn Any + Send>,
+) {
+    let message = match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map_or_else(|| String::from("unknown panic"), |m| m.to_string()),
+    };
+    if let Some(handler) = panic_handler {
+        let panic = LoopPanic {
+            event: event.cloned(),
+            message,
+        };
+        // a panic of the panic handler itself (such as a post on a dead connection) is dropped,
+        // so it cannot stop the event loop either
+        let _ = catch_unwind(AssertUnwindSafe(|| handler(mc.clone(), &panic)));
+    }
+}
+/// the handle of a running event loop
+///
+/// the event loop is stopped when the handle is dropped
+pub struct EventLoopHandle {
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<EventLoop>>,
+}
+impl EventLoopHandle {
+    /// whether the event loop thread is still running
+    pub fn is_running(&self) -> bool {
+        self.thread.as_ref().is_some_and(|t| !t.is_finished())
+    }
+    /// stop the event loop after the current poll and give it back
+    pub fn stop(mut self) -> EventLoop {
+        self.request_stop();
+        self.thread
+            .take()
+            .expect("Event loop already stopped")
+            .join()
+            .expect("Event loop thread panicked")
+    }
+    fn request_stop(&self) {
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(t) = &self.thread {
+            t.thread().unpark();
+        }
+    }
+}
+impl Drop for EventLoopHandle {
+    fn drop(&mut self) {
+        self.request_stop();
+        if let Some(t) = self.thread.take() {
+            let _ = t.join();
+        }
+    }
+}
@@ -1,51 +1,565 @@
-use std::any::Any;
+//! The events module of mcpi-rs
+//! decode the replies of `events.*` and `player.events.*`
+//!
+//! every event in a reply is separated by `|`
+//!
+//! # Example
+//!
+//! ```
+//! use mcpi_rs::prelude::*;
+//!
+//! let hits = BlockEvent::decode_list("1,2,3,1,42|4,5,6,0,42|\n");
+//! assert_eq!(hits.len(), 2);
+//! assert_eq!(hits[1].pos, BlockPos::new(4, 5, 6));
+//!
+//! # #[cfg(feature = "serde")]
+//! # {
+//! // with the cargo feature `serde`, events can be logged and read back
+//! let json = serde_json::to_string(&hits[1]).unwrap();
+//! assert_eq!(json, r#"{"pos":{"x":4,"y":5,"z":6},"face":"Down","entity_id":42}"#);
+//! assert_eq!(serde_json::from_str::<BlockEvent>(&json).unwrap(), hits[1]);
+//! # }
+//! ```
+use crate::items::Block;
+use crate::position::BlockPos;
+use std::fmt::{Display, Formatter};
 
+/// the protocol value RaspberryJuice sends for a face other than the six sides
+const UNKNOWN_FACE: i32 = 7;
+
+/// the face of a block
+///
+/// the protocol value of a face is the index of it (0 = down ... 5 = east)
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let hit = BlockEvent::decode("10,64,-3,1,7");
+/// assert_eq!(hit.face, Some(BlockFace::Up));
+///
+/// // the block on top of the hit one
+/// assert_eq!(BlockFace::Up.adjacent(hit.pos), BlockPos::new(10, 65, -3));
+/// ```
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BlockFace {
+    /// -y
+    Down,
+    /// +y
+    Up,
+    /// -z
+    North,
+    /// +z
+    South,
+    /// -x
+    West,
+    /// +x
+    East,
+}
+impl BlockFace {
+    /// every face, ordered by protocol value
+    pub const ALL: [BlockFace; 6] = [
+        BlockFace::Down,
+        BlockFace::Up,
+        BlockFace::North,
+        BlockFace::South,
+        BlockFace::West,
+        BlockFace::East,
+    ];
+    /// get the face of a protocol value
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(BlockFace::from_id(4), Some(BlockFace::West));
+    /// assert_eq!(BlockFace::from_id(6), None);
+    /// ```
+    pub fn from_id(id: i32) -> Option<BlockFace> {
+        BlockFace::ALL.iter().copied().find(|f| f.id() == id)
+    }
+    /// get the protocol value of the face
+    pub fn id(self) -> i32 {
+        self as i32
+    }
+    /// get the unit vector pointing out of the face
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(BlockFace::North.offset(), BlockPos::new(0, 0, -1));
+    /// ```
+    pub fn offset(self) -> BlockPos {
+        match self {
+            BlockFace::Down => BlockPos::new(0, -1, 0),
+            BlockFace::Up => BlockPos::new(0, 1, 0),
+            BlockFace::North => BlockPos::new(0, 0, -1),
+            BlockFace::South => BlockPos::new(0, 0, 1),
+            BlockFace::West => BlockPos::new(-1, 0, 0),
+            BlockFace::East => BlockPos::new(1, 0, 0),
+        }
+    }
+    /// get the position next to `pos` on this face
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(BlockFace::East.adjacent((1, 2, 3)), BlockPos::new(2, 2, 3));
+    /// ```
+    pub fn adjacent(self, pos: impl Into<BlockPos>) -> BlockPos {
+        pos.into() + self.offset()
+    }
+    /// get the face on the other side of the block
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(BlockFace::Up.opposite(), BlockFace::Down);
+    /// assert_eq!(BlockFace::West.opposite(), BlockFace::East);
+    /// ```
+    pub fn opposite(self) -> BlockFace {
+        BlockFace::ALL[(self.id() ^ 1) as usize]
+    }
+    /// convert a protocol value string to a face (`None` if it is not one of the six sides)
+    fn decode(s: &str) -> Option<BlockFace> {
+        s.trim().parse().ok().and_then(BlockFace::from_id)
+    }
+    /// get the protocol value of a face that may be unknown (RaspberryJuice sends 7 for
+    /// any face other than the six sides)
+    fn encode(face: Option<BlockFace>) -> i32 {
+        face.map_or(UNKNOWN_FACE, BlockFace::id)
+    }
+}
+impl Display for BlockFace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+/// a block was hit with a sword
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BlockEvent {
-    pos: (i32, i32, i32),
-    face: i32,
-    entity_id: i32,
+    pub pos: BlockPos,
+    /// the hit face (`None` if the server reports a face other than the six sides)
+    pub face: Option<BlockFace>,
+    pub entity_id: i32,
 }
 impl BlockEvent {
-    pub fn hit(pos: (i32, i32, i32), face: i32, entity_id: i32) -> BlockEvent {
+    pub fn hit(
+        pos: impl Into<BlockPos>,
+        face: impl Into<Option<BlockFace>>,
+        entity_id: i32,
+    ) -> BlockEvent {
         BlockEvent {
-            pos,
-            face,
+            pos: pos.into(),
+            face: face.into(),
             entity_id,
         }
     }
+    /// convert a `x,y,z,face,entityId` string to a block event
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(BlockEvent::decode("10,64,-3,1,7"),BlockEvent::hit((10,64,-3),BlockFace::Up,7));
+    ///
+    /// // RaspberryJuice sends 7 for a face other than the six sides
+    /// assert_eq!(BlockEvent::decode("10,64,-3,7,7").face, None);
+    /// ```
+    pub fn decode(s: impl ToString) -> BlockEvent {
+        let s = s.to_string();
+        let vec = s.trim().split(',').collect::<Vec<&str>>();
+        BlockEvent::hit(
+            decode_xyz(vec[..3].join(",")),
+            BlockFace::decode(vec[3]),
+            vec[4].parse().expect("Failed to parse entity id"),
+        )
+    }
+    /// get the position next to the hit block on the hit face
+    ///
+    /// this is where a block should be placed to "build on" the hit block
+    /// (`None` if the face is unknown)
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let hit = BlockEvent::hit((0, 64, 0), BlockFace::South, 7);
+    /// assert_eq!(hit.adjacent_pos(), Some(BlockPos::new(0, 64, 1)));
+    /// ```
+    pub fn adjacent_pos(&self) -> Option<BlockPos> {
+        self.face.map(|face| face.adjacent(self.pos))
+    }
+    /// convert the reply of `events.block.hits` to block events
+    pub fn decode_list(s: impl ToString) -> Vec<BlockEvent> {
+        split_events(s)
+            .into_iter()
+            .map(BlockEvent::decode)
+            .collect()
+    }
 }
-impl ToString for BlockEvent {
-    fn to_string(&self) -> String {
-        format!(
-            "BlockEvent({:?},{},{},{},{},{}",
-            self.type_id(),
-            self.pos.0,
-            self.pos.1,
-            self.pos.2,
-            self.face,
+impl Display for BlockEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BlockEvent(Hit,{},{},{})",
+            self.pos,
+            BlockFace::encode(self.face),
             self.entity_id
         )
     }
 }
-pub fn decode_xyz(encoded: String) {
-    let mut ret: (i32, i32, i32) = (0, 0, 0);
-    let mut cnt = 0;
-    for i in encoded.split("\\,") {
-        if i.is_empty() || i == " " || i == "\n" {
-            continue;
-        }
-        match cnt {
-            0 => {
-                ret.0 = i.parse().expect("Failed to parse");
-            }
-            1 => {
-                ret.1 = i.parse().expect("Failed to parse");
-            }
-            2 => {
-                ret.2 = i.parse().expect("Failed to parse");
-            }
-            _ => break,
-        };
-        cnt += 1;
+/// a player posted a message to the chat
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChatEvent {
+    pub entity_id: i32,
+    pub message: String,
+}
+impl ChatEvent {
+    pub fn post(entity_id: i32, message: impl ToString) -> ChatEvent {
+        ChatEvent {
+            entity_id,
+            message: message.to_string(),
+        }
+    }
+    /// convert a `entityId,message` string to a chat event
+    ///
+    /// the message may contain commas
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let e = ChatEvent::decode("3,hello, world");
+    /// assert_eq!(e.entity_id, 3);
+    /// assert_eq!(e.message, "hello, world");
+    /// ```
+    pub fn decode(s: impl ToString) -> ChatEvent {
+        let s = s.to_string();
+        let s = s.trim_end_matches(['\n', '\r']);
+        let (id, message) = s.split_once(',').unwrap_or((s, ""));
+        ChatEvent::post(id.parse().expect("Failed to parse entity id"), message)
+    }
+    /// convert the reply of `events.chat.posts` to chat events
+    pub fn decode_list(s: impl ToString) -> Vec<ChatEvent> {
+        split_events(s).into_iter().map(ChatEvent::decode).collect()
+    }
+}
+impl Display for ChatEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ChatEvent(Post,{},{})", self.entity_id, self.message)
+    }
+}
+/// a projectile shot by a player hit a block or an entity
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProjectileEvent {
+    pub pos: BlockPos,
+    /// the hit face (`None` if the server reports a face other than the six sides)
+    pub face: Option<BlockFace>,
+    /// the name of the player who shot the projectile
+    pub shooter: String,
+    /// the name of the entity which was hit (`None` if a block was hit)
+    pub target: Option<String>,
+}
+impl ProjectileEvent {
+    pub fn hit(
+        pos: impl Into<BlockPos>,
+        face: impl Into<Option<BlockFace>>,
+        shooter: impl ToString,
+        target: Option<String>,
+    ) -> ProjectileEvent {
+        ProjectileEvent {
+            pos: pos.into(),
+            face: face.into(),
+            shooter: shooter.to_string(),
+            target,
+        }
+    }
+    /// convert a `x,y,z,face,shooterName,targetName` string to a projectile event
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let e = ProjectileEvent::decode("1,2,3,1,Steve,");
+    /// assert_eq!(e.shooter, "Steve");
+    /// assert_eq!(e.target, None);
+    ///
+    /// let e = ProjectileEvent::decode("1,2,3,1,Steve,Alex");
+    /// assert_eq!(e.target, Some(String::from("Alex")));
+    /// ```
+    pub fn decode(s: impl ToString) -> ProjectileEvent {
+        let s = s.to_string();
+        let vec = s.trim().splitn(6, ',').collect::<Vec<&str>>();
+        ProjectileEvent::hit(
+            decode_xyz(vec[..3].join(",")),
+            BlockFace::decode(vec[3]),
+            vec.get(4).copied().unwrap_or(""),
+            vec.get(5).filter(|t| !t.is_empty()).map(|t| t.to_string()),
+        )
+    }
+    /// convert the reply of `events.projectile.hits` to projectile events
+    pub fn decode_list(s: impl ToString) -> Vec<ProjectileEvent> {
+        split_events(s)
+            .into_iter()
+            .map(ProjectileEvent::decode)
+            .collect()
+    }
+}
+impl Display for ProjectileEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ProjectileEvent(Hit,{},{},{},{})",
+            self.pos,
+            BlockFace::encode(self.face),
+            self.shooter,
+            self.target.as_deref().unwrap_or("")
+        )
+    }
+}
+/// a player joined or left the server (synthesized by `PlayerWatcher`)
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerEvent {
+    pub entity_id: i32,
+    /// the tile of the player when it joined, or the last known tile when it left
+    pub pos: BlockPos,
+}
+impl Display for PlayerEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PlayerEvent({},{})", self.entity_id, self.pos)
+    }
+}
+/// a player moved to another tile (synthesized by `PlayerWatcher`)
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoveEvent {
+    pub entity_id: i32,
+    pub from: BlockPos,
+    pub to: BlockPos,
+}
+impl Display for MoveEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MoveEvent({},{},{})", self.entity_id, self.from, self.to)
+    }
+}
+/// a block changed (synthesized by `RegionWatcher`)
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlockChangeEvent {
+    pub pos: BlockPos,
+    pub old: Block,
+    pub new: Block,
+}
+impl Display for BlockChangeEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BlockChangeEvent({},{},{})",
+            self.pos, self.old, self.new
+        )
+    }
+}
+/// the kind of an event
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EventKind {
+    BlockHit,
+    ChatPost,
+    ProjectileHit,
+    PlayerJoined,
+    PlayerLeft,
+    PlayerMovedTile,
+    BlockChanged,
+}
+/// any event reported by the server or synthesized by a watcher
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Event {
+    BlockHit(BlockEvent),
+    ChatPost(ChatEvent),
+    ProjectileHit(ProjectileEvent),
+    PlayerJoined(PlayerEvent),
+    PlayerLeft(PlayerEvent),
+    PlayerMovedTile(MoveEvent),
+    BlockChanged(BlockChangeEvent),
+}
+impl Event {
+    /// get the kind of the event
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let e = Event::ChatPost(ChatEvent::post(1, "hi"));
+    /// assert_eq!(e.kind(), EventKind::ChatPost);
+    /// ```
+    pub fn kind(&self) -> EventKind {
+        match self {
+            Event::BlockHit(_) => EventKind::BlockHit,
+            Event::ChatPost(_) => EventKind::ChatPost,
+            Event::ProjectileHit(_) => EventKind::ProjectileHit,
+            Event::PlayerJoined(_) => EventKind::PlayerJoined,
+            Event::PlayerLeft(_) => EventKind::PlayerLeft,
+            Event::PlayerMovedTile(_) => EventKind::PlayerMovedTile,
+            Event::BlockChanged(_) => EventKind::BlockChanged,
+        }
+    }
+    /// get the position of the event (`None` if the event has no position)
+    ///
+    /// for a move this is the new tile
+    pub fn pos(&self) -> Option<BlockPos> {
+        match self {
+            Event::BlockHit(e) => Some(e.pos),
+            Event::ChatPost(_) => None,
+            Event::ProjectileHit(e) => Some(e.pos),
+            Event::PlayerJoined(e) | Event::PlayerLeft(e) => Some(e.pos),
+            Event::PlayerMovedTile(e) => Some(e.to),
+            Event::BlockChanged(e) => Some(e.pos),
+        }
+    }
+    /// get the entity id of the player who caused the event (`None` if the server only reports a name)
+    pub fn entity_id(&self) -> Option<i32> {
+        match self {
+            Event::BlockHit(e) => Some(e.entity_id),
+            Event::ChatPost(e) => Some(e.entity_id),
+            Event::ProjectileHit(_) => None,
+            Event::PlayerJoined(e) | Event::PlayerLeft(e) => Some(e.entity_id),
+            Event::PlayerMovedTile(e) => Some(e.entity_id),
+            Event::BlockChanged(_) => None,
+        }
+    }
+    /// get the name of the player who caused the event (`None` if the server only reports an id)
+    pub fn player_name(&self) -> Option<&str> {
+        match self {
+            Event::ProjectileHit(e) => Some(&e.shooter),
+            _ => None,
+        }
+    }
+}
+impl Display for Event {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Event::BlockHit(e) => e.fmt(f),
+            Event::ChatPost(e) => e.fmt(f),
+            Event::ProjectileHit(e) => e.fmt(f),
+            Event::PlayerJoined(e) => write!(f, "Joined{}", e),
+            Event::PlayerLeft(e) => write!(f, "Left{}", e),
+            Event::PlayerMovedTile(e) => e.fmt(f),
+            Event::BlockChanged(e) => e.fmt(f),
+        }
+    }
+}
+/// convert a `x,y,z` string to a `BlockPos`
+/// # Example
+/// ```
+/// use mcpi_rs::events::decode_xyz;
+/// use mcpi_rs::position::BlockPos;
+///
+/// assert_eq!(decode_xyz("1,-2,3"),BlockPos::new(1,-2,3));
+/// ```
+pub fn decode_xyz(encoded: impl ToString) -> BlockPos {
+    let s = encoded.to_string();
+    let vec = s
+        .split(',')
+        .map(str::trim)
+        .filter(|i| !i.is_empty())
+        .collect::<Vec<&str>>();
+    BlockPos::new(
+        vec[0].parse().expect("Failed to parse"),
+        vec[1].parse().expect("Failed to parse"),
+        vec[2].parse().expect("Failed to parse"),
+    )
+}
+/// split a reply into the events it contains
+fn split_events(s: impl ToString) -> Vec<String> {
+    s.to_string()
+        .trim_end_matches(['\n', '\r'])
+        .split('|')
+        .filter(|e| !e.trim().is_empty())
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_block_hits() {
+        assert!(BlockEvent::decode_list("").is_empty());
+        assert!(BlockEvent::decode_list("\n").is_empty());
+        let v = BlockEvent::decode_list("1,64,-2,4,17|-5,0,3,7,18\n");
+        assert_eq!(
+            v,
+            vec![
+                BlockEvent::hit((1, 64, -2), BlockFace::West, 17),
+                BlockEvent::hit((-5, 0, 3), None, 18),
+            ]
+        );
+        assert_eq!(v[1].adjacent_pos(), None);
+        assert_eq!(v[1].to_string(), "BlockEvent(Hit,-5,0,3,7,18)");
+    }
+
+    #[test]
+    fn decode_chat_posts() {
+        let v = ChatEvent::decode_list("3,hello, world|4,|5, spaced \r\n");
+        assert_eq!(
+            v,
+            vec![
+                ChatEvent::post(3, "hello, world"),
+                ChatEvent::post(4, ""),
+                ChatEvent::post(5, " spaced "),
+            ]
+        );
+    }
+
+    #[test]
+    fn decode_projectile_hits() {
+        let v = ProjectileEvent::decode_list("1,2,3,1,Steve,|-1,70,8,9,Alex,Zombie\n");
+        assert_eq!(
+            v,
+            vec![
+                ProjectileEvent::hit((1, 2, 3), BlockFace::Up, "Steve", None),
+                ProjectileEvent::hit((-1, 70, 8), None, "Alex", Some(String::from("Zombie"))),
+            ]
+        );
+        let e = Event::ProjectileHit(v[1].clone());
+        assert_eq!(e.entity_id(), None);
+        assert_eq!(e.player_name(), Some("Alex"));
+        assert_eq!(e.pos(), Some(BlockPos::new(-1, 70, 8)));
+    }
+
+    #[test]
+    fn decode_xyz_ignores_spaces_and_empty_parts() {
+        assert_eq!(decode_xyz(" 1, -2 ,3\n"), BlockPos::new(1, -2, 3));
+        assert_eq!(decode_xyz("1,,-2,3"), BlockPos::new(1, -2, 3));
+    }
+
+    #[test]
+    fn faces() {
+        for (id, &face) in BlockFace::ALL.iter().enumerate() {
+            assert_eq!(face.id(), id as i32);
+            assert_eq!(BlockFace::decode(&id.to_string()), Some(face));
+            assert_eq!(BlockFace::encode(Some(face)), id as i32);
+            assert_eq!(face.opposite().opposite(), face);
+            assert_ne!(face.opposite(), face);
+            assert_eq!(
+                face.offset() + face.opposite().offset(),
+                BlockPos::new(0, 0, 0)
+            );
+            assert_eq!(face.offset().distance_squared(BlockPos::new(0, 0, 0)), 1);
+        }
+        for s in ["-1", "6", "7", "up", ""] {
+            assert_eq!(BlockFace::decode(s), None, "{:?}", s);
+        }
+        assert_eq!(BlockFace::encode(None), UNKNOWN_FACE);
+    }
+
+    #[test]
+    fn adjacent_pos() {
+        let hit = BlockEvent::hit((0, 64, 0), BlockFace::Down, 1);
+        assert_eq!(hit.adjacent_pos(), Some(BlockPos::new(0, 63, 0)));
+        let hit = BlockEvent::hit((0, 64, 0), BlockFace::North, 1);
+        assert_eq!(hit.adjacent_pos(), Some(BlockPos::new(0, 64, -1)));
     }
 }
@@ -0,0 +1,237 @@
+//! conversions between the position types and the ones of other math crates,
+//! and the serde implementations that cannot be derived
+//!
+//! each conversion is enabled by the cargo feature named after the crate
+//! (`glam`, `nalgebra`, `mint`), so generator output can be passed directly
+//! to every method taking a position
+#[cfg(any(feature = "glam", feature = "nalgebra", feature = "mint"))]
+use crate::position::{BlockPos, Vec3};
+
+#[cfg(feature = "glam")]
+mod glam_impl {
+    use super::*;
+
+    impl From<glam::IVec3> for BlockPos {
+        fn from(v: glam::IVec3) -> BlockPos {
+            BlockPos::new(v.x, v.y, v.z)
+        }
+    }
+    impl From<BlockPos> for glam::IVec3 {
+        fn from(pos: BlockPos) -> glam::IVec3 {
+            glam::IVec3::new(pos.x, pos.y, pos.z)
+        }
+    }
+    impl From<glam::DVec3> for Vec3 {
+        fn from(v: glam::DVec3) -> Vec3 {
+            Vec3::new(v.x, v.y, v.z)
+        }
+    }
+    impl From<Vec3> for glam::DVec3 {
+        fn from(pos: Vec3) -> glam::DVec3 {
+            glam::DVec3::new(pos.x, pos.y, pos.z)
+        }
+    }
+    impl From<glam::Vec3> for Vec3 {
+        fn from(v: glam::Vec3) -> Vec3 {
+            Vec3::new(v.x as f64, v.y as f64, v.z as f64)
+        }
+    }
+    impl From<Vec3> for glam::Vec3 {
+        fn from(pos: Vec3) -> glam::Vec3 {
+            glam::Vec3::new(pos.x as f32, pos.y as f32, pos.z as f32)
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl {
+    use super::*;
+    use nalgebra::{Point3, Vector3};
+
+    impl From<Point3<i32>> for BlockPos {
+        fn from(p: Point3<i32>) -> BlockPos {
+            BlockPos::new(p.x, p.y, p.z)
+        }
+    }
+    impl From<BlockPos> for Point3<i32> {
+        fn from(pos: BlockPos) -> Point3<i32> {
+            Point3::new(pos.x, pos.y, pos.z)
+        }
+    }
+    impl From<Vector3<i32>> for BlockPos {
+        fn from(v: Vector3<i32>) -> BlockPos {
+            BlockPos::new(v.x, v.y, v.z)
+        }
+    }
+    impl From<BlockPos> for Vector3<i32> {
+        fn from(pos: BlockPos) -> Vector3<i32> {
+            Vector3::new(pos.x, pos.y, pos.z)
+        }
+    }
+    impl From<Point3<f64>> for Vec3 {
+        fn from(p: Point3<f64>) -> Vec3 {
+            Vec3::new(p.x, p.y, p.z)
+        }
+    }
+    impl From<Vec3> for Point3<f64> {
+        fn from(pos: Vec3) -> Point3<f64> {
+            Point3::new(pos.x, pos.y, pos.z)
+        }
+    }
+    impl From<Vector3<f64>> for Vec3 {
+        fn from(v: Vector3<f64>) -> Vec3 {
+            Vec3::new(v.x, v.y, v.z)
+        }
+    }
+    impl From<Vec3> for Vector3<f64> {
+        fn from(pos: Vec3) -> Vector3<f64> {
+            Vector3::new(pos.x, pos.y, pos.z)
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+mod mint_impl {
+    use super::*;
+
+    impl From<mint::Point3<i32>> for BlockPos {
+        fn from(p: mint::Point3<i32>) -> BlockPos {
+            BlockPos::new(p.x, p.y, p.z)
+        }
+    }
+    impl From<BlockPos> for mint::Point3<i32> {
+        fn from(pos: BlockPos) -> mint::Point3<i32> {
+            mint::Point3 {
+                x: pos.x,
+                y: pos.y,
+                z: pos.z,
+            }
+        }
+    }
+    impl From<mint::Vector3<i32>> for BlockPos {
+        fn from(v: mint::Vector3<i32>) -> BlockPos {
+            BlockPos::new(v.x, v.y, v.z)
+        }
+    }
+    impl From<BlockPos> for mint::Vector3<i32> {
+        fn from(pos: BlockPos) -> mint::Vector3<i32> {
+            mint::Vector3 {
+                x: pos.x,
+                y: pos.y,
+                z: pos.z,
+            }
+        }
+    }
+    impl From<mint::Point3<f64>> for Vec3 {
+        fn from(p: mint::Point3<f64>) -> Vec3 {
+            Vec3::new(p.x, p.y, p.z)
+        }
+    }
+    impl From<Vec3> for mint::Point3<f64> {
+        fn from(pos: Vec3) -> mint::Point3<f64> {
+            mint::Point3 {
+                x: pos.x,
+                y: pos.y,
+                z: pos.z,
+            }
+        }
+    }
+    impl From<mint::Vector3<f64>> for Vec3 {
+        fn from(v: mint::Vector3<f64>) -> Vec3 {
+            Vec3::new(v.x, v.y, v.z)
+        }
+    }
+    impl From<Vec3> for mint::Vector3<f64> {
+        fn from(pos: Vec3) -> mint::Vector3<f64> {
+            mint::Vector3 {
+                x: pos.x,
+                y: pos.y,
+                z: pos.z,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use crate::items::{BlockInfo, Item};
+    use crate::position::BlockPos;
+    use crate::region::Region;
+    use crate::states::BlockState;
+    use serde::de::{self, Deserializer, Visitor};
+    use serde::ser::{SerializeStruct, Serializer};
+    use serde::{Deserialize, Serialize};
+    use std::convert::TryFrom;
+    use std::fmt::Formatter;
+
+    /// items are written by canonical name, or by id if they are not in the catalog
+    impl Serialize for Item {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self.name() {
+                Some(name) => serializer.serialize_str(name),
+                None => serializer.serialize_u32(self.clone().unwrap()),
+            }
+        }
+    }
+    /// items are read from any name `str::parse` accepts, or from an id
+    impl<'de> Deserialize<'de> for Item {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Item, D::Error> {
+            struct ItemVisitor;
+            impl Visitor<'_> for ItemVisitor {
+                type Value = Item;
+                fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                    f.write_str("a block name or id")
+                }
+                fn visit_str<E: de::Error>(self, v: &str) -> Result<Item, E> {
+                    v.parse().map_err(E::custom)
+                }
+                fn visit_u64<E: de::Error>(self, v: u64) -> Result<Item, E> {
+                    u32::try_from(v)
+                        .map(Item::Id)
+                        .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+                }
+                fn visit_i64<E: de::Error>(self, v: i64) -> Result<Item, E> {
+                    u32::try_from(v)
+                        .map(Item::Id)
+                        .map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+                }
+            }
+            deserializer.deserialize_any(ItemVisitor)
+        }
+    }
+    impl Serialize for BlockInfo {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut s = serializer.serialize_struct("BlockInfo", 4)?;
+            s.serialize_field("id", &self.id())?;
+            s.serialize_field("name", self.name())?;
+            s.serialize_field("display_name", self.display_name())?;
+            s.serialize_field("data_values", &self.data_values())?;
+            s.end()
+        }
+    }
+    /// regions are read from any two corners
+    impl<'de> Deserialize<'de> for Region {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Region, D::Error> {
+            #[derive(Deserialize)]
+            #[serde(rename = "Region")]
+            struct Corners {
+                min: BlockPos,
+                max: BlockPos,
+            }
+            let corners = Corners::deserialize(deserializer)?;
+            Ok(Region::new(corners.min, corners.max))
+        }
+    }
+    /// block states are written as `namespace:name[key=value,...]`
+    impl Serialize for BlockState {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_str(self)
+        }
+    }
+    impl<'de> Deserialize<'de> for BlockState {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<BlockState, D::Error> {
+            let s = String::deserialize(deserializer)?;
+            s.parse().map_err(de::Error::custom)
+        }
+    }
+}
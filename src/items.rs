@@ -1,5 +1,40 @@
+//! The items module of mcpi-rs
+//! the block and item ids of the legacy (pre-1.13) format
+//!
+//! every id from 0 to 255 has a constant and an entry in the block catalog ([`BlockInfo`])
+//! with its canonical name, its display name and the data values it accepts
+//!
+//! the canonical names are the 1.12 registry names (`"lapis_ore"`, `"golden_rail"`, `"web"`),
+//! as shown with F3 and accepted by `/setblock`; the lowercase constant names
+//! (`"lapis_lazuli_ore"`, `"rail_powered"`, `"cobweb"`) are aliases when no canonical name
+//! matches, so `"snow"` is [`SNOW_BLOCK`] (80) and not [`SNOW`] (78, the snow layer)
+//!
+//! [`BEDROCK_INVISIBLE`], [`GLOWING_OBSIDIAN`] and [`NETHER_REACTOR_CORE`] only exist on
+//! Minecraft: Pi Edition; the catalog describes their ids as the Java Edition blocks
+//!
+//! with the cargo feature `serde`, items are written by canonical name (by id if they are not
+//! in the catalog) and read from a name or an id
+//!
+//! # Example
+//!
+//! ```
+//! use mcpi_rs::prelude::*;
+//!
+//! # #[cfg(feature = "serde")]
+//! # {
+//! let json = serde_json::to_string(&Block::wool(WoolColor::Red)).unwrap();
+//! assert_eq!(json, r#"{"id":"wool","data":14}"#);
+//! let block: Block = serde_json::from_str(r#"{"id":"lapis_lazuli_ore"}"#).unwrap();
+//! assert_eq!(block, Block::from_item(LAPIS_LAZULI_ORE));
+//! let block: Block = serde_json::from_str(r#"{"id":1000,"data":2}"#).unwrap();
+//! assert_eq!(serde_json::to_string(&block).unwrap(), r#"{"id":1000,"data":2}"#);
+//! # }
+//! ```
 use self::Item::Id;
-#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+use crate::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub enum Item {
     Id(u32),
 }
@@ -9,7 +44,86 @@ impl Item {
             Id(x) => x,
         }
     }
+    /// get the item by its name, the same as [`str::parse`] without the error
+    ///
+    /// canonical names win over constant names, so `"water"` is [`WATER_STATIONARY`] and every
+    /// canonical name printed by `Display` gives the same item back
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert!(Item::from_name("Diamond_Ore") == Some(DIAMOND_ORE));
+    /// assert!(Item::from_name("golden_rail") == Some(RAIL_POWERED));
+    /// assert!(Item::from_name("water") == Some(WATER_STATIONARY));
+    /// assert!(Item::from_name(&WATER_STATIONARY.to_string()) == Some(WATER_STATIONARY));
+    /// assert!(Item::from_name("unobtainium").is_none());
+    /// ```
+    pub fn from_name(name: &str) -> Option<Item> {
+        name.parse().ok()
+    }
+    /// get the catalog entry of the item, `None` for ids above 255 and the unused ids 253 and 254
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let info = LAPIS_LAZULI_ORE.info().unwrap();
+    /// assert_eq!(info.name(), "lapis_ore");
+    /// assert_eq!(info.display_name(), "Lapis Lazuli Ore");
+    /// assert!(Item::Id(253).info().is_none());
+    /// ```
+    pub fn info(&self) -> Option<&'static BlockInfo> {
+        BlockInfo::by_id(self.clone().unwrap())
+    }
+    /// get the canonical name of the item
+    pub fn name(&self) -> Option<&'static str> {
+        self.info().map(BlockInfo::name)
+    }
+}
+/// the canonical name of the item, or its id if it is not in the catalog
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// assert_eq!(DIAMOND_ORE.to_string(), "diamond_ore");
+/// assert_eq!(Item::Id(1000).to_string(), "1000");
+/// ```
+impl Display for Item {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{}", self.clone().unwrap()),
+        }
+    }
+}
+/// parse a canonical name, a constant name or a numeric id
+///
+/// names are case-insensitive and may carry a `minecraft:` prefix; canonical names win over
+/// constant names, so `"water"` is [`WATER_STATIONARY`] and `"snow"` is [`SNOW_BLOCK`]
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// assert_eq!("diamond_ore".parse::<Item>().unwrap(), DIAMOND_ORE);
+/// assert_eq!("minecraft:web".parse::<Item>().unwrap(), COBWEB);
+/// assert_eq!("cobweb".parse::<Item>().unwrap(), COBWEB);
+/// assert_eq!("35".parse::<Item>().unwrap(), WOOL);
+/// assert!("unobtainium".parse::<Item>().is_err());
+/// ```
+impl FromStr for Item {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Item, Error> {
+        let name = s.trim().to_lowercase();
+        let name = name.strip_prefix("minecraft:").unwrap_or(&name);
+        BlockInfo::by_name(name)
+            .map(BlockInfo::item)
+            .or_else(|| alias(name))
+            .or_else(|| name.parse().ok().map(Id))
+            .ok_or_else(|| Error::UnknownBlock {
+                name: s.to_string(),
+            })
+    }
 }
+
 pub const AIR: Item = Id(0);
 pub const STONE: Item = Id(1);
 pub const GRASS: Item = Id(2);
@@ -31,16 +145,31 @@ pub const IRON_ORE: Item = Id(15);
 pub const COAL_ORE: Item = Id(16);
 pub const WOOD: Item = Id(17);
 pub const LEAVES: Item = Id(18);
+pub const SPONGE: Item = Id(19);
 pub const GLASS: Item = Id(20);
-pub const LAPINS_LAZULI_ORE: Item = Id(21);
-pub const LAPINS_LAZULI_BLOCK: Item = Id(22);
+pub const LAPIS_LAZULI_ORE: Item = Id(21);
+pub const LAPIS_LAZULI_BLOCK: Item = Id(22);
+#[deprecated(note = "misspelled, use `LAPIS_LAZULI_ORE`")]
+pub const LAPINS_LAZULI_ORE: Item = LAPIS_LAZULI_ORE;
+#[deprecated(note = "misspelled, use `LAPIS_LAZULI_BLOCK`")]
+pub const LAPINS_LAZULI_BLOCK: Item = LAPIS_LAZULI_BLOCK;
+pub const DISPENSER: Item = Id(23);
 pub const SANDSTONE: Item = Id(24);
+pub const NOTE_BLOCK: Item = Id(25);
 pub const BED: Item = Id(26);
+pub const RAIL_POWERED: Item = Id(27);
+pub const RAIL_DETECTOR: Item = Id(28);
+pub const PISTON_STICKY: Item = Id(29);
 pub const COBWEB: Item = Id(30);
 pub const GRASS_TALL: Item = Id(31);
+pub const DEAD_BUSH: Item = Id(32);
+pub const PISTON: Item = Id(33);
+pub const PISTON_HEAD: Item = Id(34);
 pub const WOOL: Item = Id(35);
+pub const PISTON_MOVING: Item = Id(36);
 pub const FLOWER_YELLOW: Item = Id(37);
 pub const FLOWER_CYAN: Item = Id(38);
+pub const FLOWER_RED: Item = Id(38);
 pub const MUSHROOM_BROWN: Item = Id(39);
 pub const MUSHROOM_RED: Item = Id(40);
 pub const GOLD_BLOCK: Item = Id(41);
@@ -54,37 +183,1064 @@ pub const MOSS_STONE: Item = Id(48);
 pub const OBSIDIAN: Item = Id(49);
 pub const TORCH: Item = Id(50);
 pub const FIRE: Item = Id(51);
+pub const MOB_SPAWNER: Item = Id(52);
 pub const STAIRS_WOOD: Item = Id(53);
 pub const CHEST: Item = Id(54);
+pub const REDSTONE_WIRE: Item = Id(55);
 pub const DIAMOND_ORE: Item = Id(56);
 pub const DIAMOND_BLOCK: Item = Id(57);
 pub const CRAFTING_TABLE: Item = Id(58);
+pub const WHEAT: Item = Id(59);
 pub const FARMLAND: Item = Id(60);
 pub const FURNACE_INACTIVE: Item = Id(61);
 pub const FURNACE_ACTIVE: Item = Id(62);
+pub const SIGN_POST: Item = Id(63);
 pub const DOOR_WOOD: Item = Id(64);
 pub const LADDER: Item = Id(65);
+pub const RAIL: Item = Id(66);
 pub const STAIRS_COBBLESTONE: Item = Id(67);
+pub const SIGN_WALL: Item = Id(68);
+pub const LEVER: Item = Id(69);
+pub const PRESSURE_PLATE_STONE: Item = Id(70);
 pub const DOOR_IRON: Item = Id(71);
+pub const PRESSURE_PLATE_WOOD: Item = Id(72);
 pub const REDSTONE_ORE: Item = Id(73);
+pub const REDSTONE_ORE_GLOWING: Item = Id(74);
+pub const REDSTONE_TORCH_OFF: Item = Id(75);
+pub const REDSTONE_TORCH: Item = Id(76);
+pub const BUTTON_STONE: Item = Id(77);
+/// the snow layer (`"snow_layer"`); the name `"snow"` is [`SNOW_BLOCK`]
 pub const SNOW: Item = Id(78);
 pub const ICE: Item = Id(79);
 pub const SNOW_BLOCK: Item = Id(80);
 pub const CACTUS: Item = Id(81);
 pub const CLAY: Item = Id(82);
 pub const SUGAR_CANE: Item = Id(83);
+pub const JUKEBOX: Item = Id(84);
 pub const FENCE: Item = Id(85);
+pub const PUMPKIN: Item = Id(86);
+pub const NETHERRACK: Item = Id(87);
+pub const SOUL_SAND: Item = Id(88);
 pub const GLOWSTONE_BLOCK: Item = Id(89);
-pub const BEDROCK_INVISIBLE: Item = Id(95);
+pub const PORTAL: Item = Id(90);
+pub const JACK_O_LANTERN: Item = Id(91);
+pub const CAKE: Item = Id(92);
+pub const REPEATER: Item = Id(93);
+pub const REPEATER_POWERED: Item = Id(94);
+pub const STAINED_GLASS: Item = Id(95);
+pub const TRAPDOOR_WOOD: Item = Id(96);
+pub const MONSTER_EGG: Item = Id(97);
 pub const STONE_BRICK: Item = Id(98);
+pub const MUSHROOM_BLOCK_BROWN: Item = Id(99);
+pub const MUSHROOM_BLOCK_RED: Item = Id(100);
+pub const IRON_BARS: Item = Id(101);
 pub const GLASS_PANE: Item = Id(102);
 pub const MELON: Item = Id(103);
+pub const PUMPKIN_STEM: Item = Id(104);
+pub const MELON_STEM: Item = Id(105);
+pub const VINE: Item = Id(106);
 pub const FENCE_GATE: Item = Id(107);
+pub const STAIRS_BRICK: Item = Id(108);
+pub const STAIRS_STONE_BRICK: Item = Id(109);
+pub const MYCELIUM: Item = Id(110);
+pub const LILY_PAD: Item = Id(111);
+pub const NETHER_BRICK: Item = Id(112);
+pub const FENCE_NETHER_BRICK: Item = Id(113);
+pub const STAIRS_NETHER_BRICK: Item = Id(114);
+pub const NETHER_WART: Item = Id(115);
+pub const ENCHANTING_TABLE: Item = Id(116);
+pub const BREWING_STAND: Item = Id(117);
+pub const CAULDRON: Item = Id(118);
+pub const END_PORTAL: Item = Id(119);
+pub const END_PORTAL_FRAME: Item = Id(120);
+pub const END_STONE: Item = Id(121);
+pub const DRAGON_EGG: Item = Id(122);
+pub const REDSTONE_LAMP: Item = Id(123);
+pub const REDSTONE_LAMP_ON: Item = Id(124);
+pub const WOOD_SLAB_DOUBLE: Item = Id(125);
+pub const WOOD_SLAB: Item = Id(126);
+pub const COCOA: Item = Id(127);
+pub const STAIRS_SANDSTONE: Item = Id(128);
+pub const EMERALD_ORE: Item = Id(129);
+pub const ENDER_CHEST: Item = Id(130);
+pub const TRIPWIRE_HOOK: Item = Id(131);
+pub const TRIPWIRE: Item = Id(132);
+pub const EMERALD_BLOCK: Item = Id(133);
+pub const STAIRS_SPRUCE: Item = Id(134);
+pub const STAIRS_BIRCH: Item = Id(135);
+pub const STAIRS_JUNGLE: Item = Id(136);
+pub const COMMAND_BLOCK: Item = Id(137);
+pub const BEACON: Item = Id(138);
+pub const COBBLESTONE_WALL: Item = Id(139);
+pub const FLOWER_POT: Item = Id(140);
+pub const CARROTS: Item = Id(141);
+pub const POTATOES: Item = Id(142);
+pub const BUTTON_WOOD: Item = Id(143);
+pub const SKULL: Item = Id(144);
+pub const ANVIL: Item = Id(145);
+pub const CHEST_TRAPPED: Item = Id(146);
+pub const PRESSURE_PLATE_GOLD: Item = Id(147);
+pub const PRESSURE_PLATE_IRON: Item = Id(148);
+pub const COMPARATOR: Item = Id(149);
+pub const COMPARATOR_POWERED: Item = Id(150);
+pub const DAYLIGHT_DETECTOR: Item = Id(151);
+pub const REDSTONE_BLOCK: Item = Id(152);
+pub const QUARTZ_ORE: Item = Id(153);
+pub const HOPPER: Item = Id(154);
+pub const QUARTZ_BLOCK: Item = Id(155);
+pub const STAIRS_QUARTZ: Item = Id(156);
+pub const RAIL_ACTIVATOR: Item = Id(157);
+pub const DROPPER: Item = Id(158);
+pub const STAINED_CLAY: Item = Id(159);
+pub const STAINED_GLASS_PANE: Item = Id(160);
+pub const LEAVES2: Item = Id(161);
+pub const WOOD2: Item = Id(162);
+pub const STAIRS_ACACIA: Item = Id(163);
+pub const STAIRS_DARK_OAK: Item = Id(164);
+pub const SLIME_BLOCK: Item = Id(165);
+pub const BARRIER: Item = Id(166);
+pub const TRAPDOOR_IRON: Item = Id(167);
+pub const PRISMARINE: Item = Id(168);
+pub const SEA_LANTERN: Item = Id(169);
+pub const HAY_BLOCK: Item = Id(170);
+pub const CARPET: Item = Id(171);
+pub const HARDENED_CLAY: Item = Id(172);
+pub const COAL_BLOCK: Item = Id(173);
+pub const PACKED_ICE: Item = Id(174);
+pub const DOUBLE_PLANT: Item = Id(175);
+pub const BANNER_STANDING: Item = Id(176);
+pub const BANNER_WALL: Item = Id(177);
+pub const DAYLIGHT_DETECTOR_INVERTED: Item = Id(178);
+pub const RED_SANDSTONE: Item = Id(179);
+pub const STAIRS_RED_SANDSTONE: Item = Id(180);
+pub const STONE_SLAB2_DOUBLE: Item = Id(181);
+pub const STONE_SLAB2: Item = Id(182);
+pub const FENCE_GATE_SPRUCE: Item = Id(183);
+pub const FENCE_GATE_BIRCH: Item = Id(184);
+pub const FENCE_GATE_JUNGLE: Item = Id(185);
+pub const FENCE_GATE_DARK_OAK: Item = Id(186);
+pub const FENCE_GATE_ACACIA: Item = Id(187);
+pub const FENCE_SPRUCE: Item = Id(188);
+pub const FENCE_BIRCH: Item = Id(189);
+pub const FENCE_JUNGLE: Item = Id(190);
+pub const FENCE_DARK_OAK: Item = Id(191);
+pub const FENCE_ACACIA: Item = Id(192);
+pub const DOOR_SPRUCE: Item = Id(193);
+pub const DOOR_BIRCH: Item = Id(194);
+pub const DOOR_JUNGLE: Item = Id(195);
+pub const DOOR_ACACIA: Item = Id(196);
+pub const DOOR_DARK_OAK: Item = Id(197);
+pub const END_ROD: Item = Id(198);
+pub const CHORUS_PLANT: Item = Id(199);
+pub const CHORUS_FLOWER: Item = Id(200);
+pub const PURPUR_BLOCK: Item = Id(201);
+pub const PURPUR_PILLAR: Item = Id(202);
+pub const STAIRS_PURPUR: Item = Id(203);
+pub const PURPUR_SLAB_DOUBLE: Item = Id(204);
+pub const PURPUR_SLAB: Item = Id(205);
+pub const END_BRICKS: Item = Id(206);
+pub const BEETROOTS: Item = Id(207);
+pub const GRASS_PATH: Item = Id(208);
+pub const END_GATEWAY: Item = Id(209);
+pub const COMMAND_BLOCK_REPEATING: Item = Id(210);
+pub const COMMAND_BLOCK_CHAIN: Item = Id(211);
+pub const FROSTED_ICE: Item = Id(212);
+pub const MAGMA_BLOCK: Item = Id(213);
+pub const NETHER_WART_BLOCK: Item = Id(214);
+pub const RED_NETHER_BRICK: Item = Id(215);
+pub const BONE_BLOCK: Item = Id(216);
+pub const STRUCTURE_VOID: Item = Id(217);
+pub const OBSERVER: Item = Id(218);
+pub const SHULKER_BOX_WHITE: Item = Id(219);
+pub const SHULKER_BOX_ORANGE: Item = Id(220);
+pub const SHULKER_BOX_MAGENTA: Item = Id(221);
+pub const SHULKER_BOX_LIGHT_BLUE: Item = Id(222);
+pub const SHULKER_BOX_YELLOW: Item = Id(223);
+pub const SHULKER_BOX_LIME: Item = Id(224);
+pub const SHULKER_BOX_PINK: Item = Id(225);
+pub const SHULKER_BOX_GRAY: Item = Id(226);
+pub const SHULKER_BOX_LIGHT_GRAY: Item = Id(227);
+pub const SHULKER_BOX_CYAN: Item = Id(228);
+pub const SHULKER_BOX_PURPLE: Item = Id(229);
+pub const SHULKER_BOX_BLUE: Item = Id(230);
+pub const SHULKER_BOX_BROWN: Item = Id(231);
+pub const SHULKER_BOX_GREEN: Item = Id(232);
+pub const SHULKER_BOX_RED: Item = Id(233);
+pub const SHULKER_BOX_BLACK: Item = Id(234);
+pub const GLAZED_TERRACOTTA_WHITE: Item = Id(235);
+pub const GLAZED_TERRACOTTA_ORANGE: Item = Id(236);
+pub const GLAZED_TERRACOTTA_MAGENTA: Item = Id(237);
+pub const GLAZED_TERRACOTTA_LIGHT_BLUE: Item = Id(238);
+pub const GLAZED_TERRACOTTA_YELLOW: Item = Id(239);
+pub const GLAZED_TERRACOTTA_LIME: Item = Id(240);
+pub const GLAZED_TERRACOTTA_PINK: Item = Id(241);
+pub const GLAZED_TERRACOTTA_GRAY: Item = Id(242);
+pub const GLAZED_TERRACOTTA_LIGHT_GRAY: Item = Id(243);
+pub const GLAZED_TERRACOTTA_CYAN: Item = Id(244);
+pub const GLAZED_TERRACOTTA_PURPLE: Item = Id(245);
+pub const GLAZED_TERRACOTTA_BLUE: Item = Id(246);
+pub const GLAZED_TERRACOTTA_BROWN: Item = Id(247);
+pub const GLAZED_TERRACOTTA_GREEN: Item = Id(248);
+pub const GLAZED_TERRACOTTA_RED: Item = Id(249);
+pub const GLAZED_TERRACOTTA_BLACK: Item = Id(250);
+pub const CONCRETE: Item = Id(251);
+pub const CONCRETE_POWDER: Item = Id(252);
+pub const STRUCTURE_BLOCK: Item = Id(255);
+/// Minecraft: Pi Edition only, stained glass on Java Edition
+pub const BEDROCK_INVISIBLE: Item = Id(95);
+/// Minecraft: Pi Edition only, blue glazed terracotta on Java Edition
 pub const GLOWING_OBSIDIAN: Item = Id(246);
+/// Minecraft: Pi Edition only, brown glazed terracotta on Java Edition
 pub const NETHER_REACTOR_CORE: Item = Id(247);
-#[derive(Clone, Ord, PartialOrd, Eq, PartialEq)]
+/// a block of the legacy block catalog
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct BlockInfo {
+    id: u32,
+    name: &'static str,
+    display_name: &'static str,
+    data: u16,
+}
+impl BlockInfo {
+    const fn new(id: u32, name: &'static str, display_name: &'static str, data: u16) -> BlockInfo {
+        BlockInfo {
+            id,
+            name,
+            display_name,
+            data,
+        }
+    }
+    /// all the blocks of the catalog, sorted by id
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(BlockInfo::all().len(), 254);
+    /// assert_eq!(BlockInfo::all()[0].name(), "air");
+    /// ```
+    pub fn all() -> &'static [BlockInfo] {
+        BLOCKS
+    }
+    /// find a block by its id
+    pub fn by_id(id: u32) -> Option<&'static BlockInfo> {
+        BLOCKS
+            .binary_search_by_key(&id, |b| b.id)
+            .ok()
+            .map(|i| &BLOCKS[i])
+    }
+    /// find a block by its canonical name (lowercase, without the `minecraft:` prefix)
+    pub fn by_name(name: &str) -> Option<&'static BlockInfo> {
+        BLOCKS.iter().find(|b| b.name == name)
+    }
+    /// get the id of the block
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+    /// get the item of the block
+    pub fn item(&self) -> Item {
+        Id(self.id)
+    }
+    /// get the canonical name of the block, such as `"lapis_ore"`
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+    /// get the name of the block as the game shows it, such as `"Lapis Lazuli Ore"`
+    pub fn display_name(&self) -> &'static str {
+        self.display_name
+    }
+    /// get the data values the block accepts, in ascending order
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(STONE.info().unwrap().data_values(), vec![0, 1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(DIAMOND_ORE.info().unwrap().data_values(), vec![0]);
+    /// ```
+    pub fn data_values(&self) -> Vec<i32> {
+        (0..16).filter(|&d| self.is_valid_data(d)).collect()
+    }
+    /// check if the block accepts the data value
+    pub fn is_valid_data(&self, data: i32) -> bool {
+        (0..16).contains(&data) && self.data & (1 << data) != 0
+    }
+}
+/// the data values from `min` to `max` (inclusive) as a bit set
+const fn data_values(min: u16, max: u16) -> u16 {
+    (0xffff >> (15 - max)) & (0xffff << min)
+}
+const DATA_NONE: u16 = data_values(0, 0);
+const DATA_ALL: u16 = data_values(0, 15);
+/// down, up, north, south, west, east
+const DATA_FACING: u16 = data_values(0, 5);
+/// [`DATA_FACING`] with the powered (or extended) bit
+const DATA_FACING_POWERED: u16 = DATA_FACING | data_values(8, 13);
+/// north, south, west, east
+const DATA_HORIZONTAL: u16 = data_values(2, 5);
+/// four directions with the upside-down bit
+const DATA_STAIRS: u16 = data_values(0, 7);
+/// the lower half (direction and open bit) and the upper half (hinge and powered bit)
+const DATA_DOOR: u16 = data_values(0, 11);
+/// y, x and z logs and pillars
+const DATA_AXIS: u16 = 1 | 1 << 4 | 1 << 8;
+static BLOCKS: &[BlockInfo] = &[
+    BlockInfo::new(0, "air", "Air", DATA_NONE),
+    BlockInfo::new(1, "stone", "Stone", data_values(0, 6)),
+    BlockInfo::new(2, "grass", "Grass Block", DATA_NONE),
+    BlockInfo::new(3, "dirt", "Dirt", data_values(0, 2)),
+    BlockInfo::new(4, "cobblestone", "Cobblestone", DATA_NONE),
+    BlockInfo::new(5, "planks", "Wood Planks", data_values(0, 5)),
+    BlockInfo::new(
+        6,
+        "sapling",
+        "Sapling",
+        data_values(0, 5) | data_values(8, 13),
+    ),
+    BlockInfo::new(7, "bedrock", "Bedrock", DATA_NONE),
+    BlockInfo::new(8, "flowing_water", "Flowing Water", DATA_ALL),
+    BlockInfo::new(9, "water", "Water", DATA_ALL),
+    BlockInfo::new(10, "flowing_lava", "Flowing Lava", DATA_ALL),
+    BlockInfo::new(11, "lava", "Lava", DATA_ALL),
+    BlockInfo::new(12, "sand", "Sand", data_values(0, 1)),
+    BlockInfo::new(13, "gravel", "Gravel", DATA_NONE),
+    BlockInfo::new(14, "gold_ore", "Gold Ore", DATA_NONE),
+    BlockInfo::new(15, "iron_ore", "Iron Ore", DATA_NONE),
+    BlockInfo::new(16, "coal_ore", "Coal Ore", DATA_NONE),
+    BlockInfo::new(17, "log", "Wood", DATA_ALL),
+    BlockInfo::new(18, "leaves", "Leaves", DATA_ALL),
+    BlockInfo::new(19, "sponge", "Sponge", data_values(0, 1)),
+    BlockInfo::new(20, "glass", "Glass", DATA_NONE),
+    BlockInfo::new(21, "lapis_ore", "Lapis Lazuli Ore", DATA_NONE),
+    BlockInfo::new(22, "lapis_block", "Lapis Lazuli Block", DATA_NONE),
+    BlockInfo::new(23, "dispenser", "Dispenser", DATA_FACING_POWERED),
+    BlockInfo::new(24, "sandstone", "Sandstone", data_values(0, 2)),
+    BlockInfo::new(25, "noteblock", "Note Block", DATA_NONE),
+    BlockInfo::new(26, "bed", "Bed", DATA_ALL),
+    BlockInfo::new(27, "golden_rail", "Powered Rail", DATA_FACING_POWERED),
+    BlockInfo::new(28, "detector_rail", "Detector Rail", DATA_FACING_POWERED),
+    BlockInfo::new(29, "sticky_piston", "Sticky Piston", DATA_FACING_POWERED),
+    BlockInfo::new(30, "web", "Cobweb", DATA_NONE),
+    BlockInfo::new(31, "tallgrass", "Tall Grass", data_values(0, 2)),
+    BlockInfo::new(32, "deadbush", "Dead Bush", DATA_NONE),
+    BlockInfo::new(33, "piston", "Piston", DATA_FACING_POWERED),
+    BlockInfo::new(34, "piston_head", "Piston Head", DATA_FACING_POWERED),
+    BlockInfo::new(35, "wool", "Wool", DATA_ALL),
+    BlockInfo::new(36, "piston_extension", "Moving Piston", DATA_FACING_POWERED),
+    BlockInfo::new(37, "yellow_flower", "Dandelion", DATA_NONE),
+    BlockInfo::new(38, "red_flower", "Poppy", data_values(0, 8)),
+    BlockInfo::new(39, "brown_mushroom", "Brown Mushroom", DATA_NONE),
+    BlockInfo::new(40, "red_mushroom", "Red Mushroom", DATA_NONE),
+    BlockInfo::new(41, "gold_block", "Block of Gold", DATA_NONE),
+    BlockInfo::new(42, "iron_block", "Block of Iron", DATA_NONE),
+    BlockInfo::new(
+        43,
+        "double_stone_slab",
+        "Double Stone Slab",
+        data_values(0, 7) | data_values(8, 9) | data_values(15, 15),
+    ),
+    BlockInfo::new(44, "stone_slab", "Stone Slab", DATA_ALL),
+    BlockInfo::new(45, "brick_block", "Bricks", DATA_NONE),
+    BlockInfo::new(46, "tnt", "TNT", data_values(0, 1)),
+    BlockInfo::new(47, "bookshelf", "Bookshelf", DATA_NONE),
+    BlockInfo::new(48, "mossy_cobblestone", "Moss Stone", DATA_NONE),
+    BlockInfo::new(49, "obsidian", "Obsidian", DATA_NONE),
+    BlockInfo::new(50, "torch", "Torch", data_values(1, 5)),
+    BlockInfo::new(51, "fire", "Fire", DATA_ALL),
+    BlockInfo::new(52, "mob_spawner", "Monster Spawner", DATA_NONE),
+    BlockInfo::new(53, "oak_stairs", "Oak Wood Stairs", DATA_STAIRS),
+    BlockInfo::new(54, "chest", "Chest", DATA_HORIZONTAL),
+    BlockInfo::new(55, "redstone_wire", "Redstone Wire", DATA_ALL),
+    BlockInfo::new(56, "diamond_ore", "Diamond Ore", DATA_NONE),
+    BlockInfo::new(57, "diamond_block", "Block of Diamond", DATA_NONE),
+    BlockInfo::new(58, "crafting_table", "Crafting Table", DATA_NONE),
+    BlockInfo::new(59, "wheat", "Wheat Crops", data_values(0, 7)),
+    BlockInfo::new(60, "farmland", "Farmland", data_values(0, 7)),
+    BlockInfo::new(61, "furnace", "Furnace", DATA_HORIZONTAL),
+    BlockInfo::new(62, "lit_furnace", "Burning Furnace", DATA_HORIZONTAL),
+    BlockInfo::new(63, "standing_sign", "Sign", DATA_ALL),
+    BlockInfo::new(64, "wooden_door", "Oak Door", DATA_DOOR),
+    BlockInfo::new(65, "ladder", "Ladder", DATA_HORIZONTAL),
+    BlockInfo::new(66, "rail", "Rail", data_values(0, 9)),
+    BlockInfo::new(67, "stone_stairs", "Cobblestone Stairs", DATA_STAIRS),
+    BlockInfo::new(68, "wall_sign", "Wall Sign", DATA_HORIZONTAL),
+    BlockInfo::new(69, "lever", "Lever", DATA_ALL),
+    BlockInfo::new(
+        70,
+        "stone_pressure_plate",
+        "Stone Pressure Plate",
+        data_values(0, 1),
+    ),
+    BlockInfo::new(71, "iron_door", "Iron Door", DATA_DOOR),
+    BlockInfo::new(
+        72,
+        "wooden_pressure_plate",
+        "Wooden Pressure Plate",
+        data_values(0, 1),
+    ),
+    BlockInfo::new(73, "redstone_ore", "Redstone Ore", DATA_NONE),
+    BlockInfo::new(74, "lit_redstone_ore", "Glowing Redstone Ore", DATA_NONE),
+    BlockInfo::new(
+        75,
+        "unlit_redstone_torch",
+        "Redstone Torch (off)",
+        data_values(1, 5),
+    ),
+    BlockInfo::new(76, "redstone_torch", "Redstone Torch", data_values(1, 5)),
+    BlockInfo::new(77, "stone_button", "Stone Button", DATA_FACING_POWERED),
+    BlockInfo::new(78, "snow_layer", "Snow", data_values(0, 7)),
+    BlockInfo::new(79, "ice", "Ice", DATA_NONE),
+    BlockInfo::new(80, "snow", "Snow Block", DATA_NONE),
+    BlockInfo::new(81, "cactus", "Cactus", DATA_ALL),
+    BlockInfo::new(82, "clay", "Clay", DATA_NONE),
+    BlockInfo::new(83, "reeds", "Sugar Canes", DATA_ALL),
+    BlockInfo::new(84, "jukebox", "Jukebox", data_values(0, 1)),
+    BlockInfo::new(85, "fence", "Oak Fence", DATA_NONE),
+    BlockInfo::new(86, "pumpkin", "Pumpkin", data_values(0, 4)),
+    BlockInfo::new(87, "netherrack", "Netherrack", DATA_NONE),
+    BlockInfo::new(88, "soul_sand", "Soul Sand", DATA_NONE),
+    BlockInfo::new(89, "glowstone", "Glowstone", DATA_NONE),
+    BlockInfo::new(90, "portal", "Nether Portal", data_values(0, 2)),
+    BlockInfo::new(91, "lit_pumpkin", "Jack o'Lantern", data_values(0, 4)),
+    BlockInfo::new(92, "cake", "Cake", data_values(0, 6)),
+    BlockInfo::new(93, "unpowered_repeater", "Redstone Repeater", DATA_ALL),
+    BlockInfo::new(
+        94,
+        "powered_repeater",
+        "Powered Redstone Repeater",
+        DATA_ALL,
+    ),
+    BlockInfo::new(95, "stained_glass", "Stained Glass", DATA_ALL),
+    BlockInfo::new(96, "trapdoor", "Wooden Trapdoor", DATA_ALL),
+    BlockInfo::new(97, "monster_egg", "Stone Monster Egg", data_values(0, 5)),
+    BlockInfo::new(98, "stonebrick", "Stone Bricks", data_values(0, 3)),
+    BlockInfo::new(
+        99,
+        "brown_mushroom_block",
+        "Brown Mushroom Block",
+        data_values(0, 10) | data_values(14, 15),
+    ),
+    BlockInfo::new(
+        100,
+        "red_mushroom_block",
+        "Red Mushroom Block",
+        data_values(0, 10) | data_values(14, 15),
+    ),
+    BlockInfo::new(101, "iron_bars", "Iron Bars", DATA_NONE),
+    BlockInfo::new(102, "glass_pane", "Glass Pane", DATA_NONE),
+    BlockInfo::new(103, "melon_block", "Melon", DATA_NONE),
+    BlockInfo::new(104, "pumpkin_stem", "Pumpkin Stem", data_values(0, 7)),
+    BlockInfo::new(105, "melon_stem", "Melon Stem", data_values(0, 7)),
+    BlockInfo::new(106, "vine", "Vines", DATA_ALL),
+    BlockInfo::new(107, "fence_gate", "Oak Fence Gate", DATA_ALL),
+    BlockInfo::new(108, "brick_stairs", "Brick Stairs", DATA_STAIRS),
+    BlockInfo::new(109, "stone_brick_stairs", "Stone Brick Stairs", DATA_STAIRS),
+    BlockInfo::new(110, "mycelium", "Mycelium", DATA_NONE),
+    BlockInfo::new(111, "waterlily", "Lily Pad", DATA_NONE),
+    BlockInfo::new(112, "nether_brick", "Nether Brick", DATA_NONE),
+    BlockInfo::new(113, "nether_brick_fence", "Nether Brick Fence", DATA_NONE),
+    BlockInfo::new(
+        114,
+        "nether_brick_stairs",
+        "Nether Brick Stairs",
+        DATA_STAIRS,
+    ),
+    BlockInfo::new(115, "nether_wart", "Nether Wart", data_values(0, 3)),
+    BlockInfo::new(116, "enchanting_table", "Enchantment Table", DATA_NONE),
+    BlockInfo::new(117, "brewing_stand", "Brewing Stand", data_values(0, 7)),
+    BlockInfo::new(118, "cauldron", "Cauldron", data_values(0, 3)),
+    BlockInfo::new(119, "end_portal", "End Portal", DATA_NONE),
+    BlockInfo::new(
+        120,
+        "end_portal_frame",
+        "End Portal Frame",
+        data_values(0, 7),
+    ),
+    BlockInfo::new(121, "end_stone", "End Stone", DATA_NONE),
+    BlockInfo::new(122, "dragon_egg", "Dragon Egg", DATA_NONE),
+    BlockInfo::new(123, "redstone_lamp", "Redstone Lamp", DATA_NONE),
+    BlockInfo::new(124, "lit_redstone_lamp", "Redstone Lamp (on)", DATA_NONE),
+    BlockInfo::new(
+        125,
+        "double_wooden_slab",
+        "Double Wood Slab",
+        data_values(0, 5),
+    ),
+    BlockInfo::new(
+        126,
+        "wooden_slab",
+        "Wood Slab",
+        data_values(0, 5) | data_values(8, 13),
+    ),
+    BlockInfo::new(127, "cocoa", "Cocoa", data_values(0, 11)),
+    BlockInfo::new(128, "sandstone_stairs", "Sandstone Stairs", DATA_STAIRS),
+    BlockInfo::new(129, "emerald_ore", "Emerald Ore", DATA_NONE),
+    BlockInfo::new(130, "ender_chest", "Ender Chest", DATA_HORIZONTAL),
+    BlockInfo::new(131, "tripwire_hook", "Tripwire Hook", DATA_ALL),
+    BlockInfo::new(132, "tripwire", "Tripwire", DATA_ALL),
+    BlockInfo::new(133, "emerald_block", "Block of Emerald", DATA_NONE),
+    BlockInfo::new(134, "spruce_stairs", "Spruce Wood Stairs", DATA_STAIRS),
+    BlockInfo::new(135, "birch_stairs", "Birch Wood Stairs", DATA_STAIRS),
+    BlockInfo::new(136, "jungle_stairs", "Jungle Wood Stairs", DATA_STAIRS),
+    BlockInfo::new(137, "command_block", "Command Block", DATA_FACING_POWERED),
+    BlockInfo::new(138, "beacon", "Beacon", DATA_NONE),
+    BlockInfo::new(
+        139,
+        "cobblestone_wall",
+        "Cobblestone Wall",
+        data_values(0, 1),
+    ),
+    BlockInfo::new(140, "flower_pot", "Flower Pot", DATA_ALL),
+    BlockInfo::new(141, "carrots", "Carrots", data_values(0, 7)),
+    BlockInfo::new(142, "potatoes", "Potatoes", data_values(0, 7)),
+    BlockInfo::new(143, "wooden_button", "Wooden Button", DATA_FACING_POWERED),
+    BlockInfo::new(144, "skull", "Mob Head", DATA_FACING_POWERED),
+    BlockInfo::new(145, "anvil", "Anvil", data_values(0, 11)),
+    BlockInfo::new(146, "trapped_chest", "Trapped Chest", DATA_HORIZONTAL),
+    BlockInfo::new(
+        147,
+        "light_weighted_pressure_plate",
+        "Weighted Pressure Plate (Light)",
+        DATA_ALL,
+    ),
+    BlockInfo::new(
+        148,
+        "heavy_weighted_pressure_plate",
+        "Weighted Pressure Plate (Heavy)",
+        DATA_ALL,
+    ),
+    BlockInfo::new(149, "unpowered_comparator", "Redstone Comparator", DATA_ALL),
+    BlockInfo::new(
+        150,
+        "powered_comparator",
+        "Powered Redstone Comparator",
+        DATA_ALL,
+    ),
+    BlockInfo::new(151, "daylight_detector", "Daylight Sensor", DATA_ALL),
+    BlockInfo::new(152, "redstone_block", "Block of Redstone", DATA_NONE),
+    BlockInfo::new(153, "quartz_ore", "Nether Quartz Ore", DATA_NONE),
+    BlockInfo::new(154, "hopper", "Hopper", DATA_FACING_POWERED),
+    BlockInfo::new(155, "quartz_block", "Block of Quartz", data_values(0, 4)),
+    BlockInfo::new(156, "quartz_stairs", "Quartz Stairs", DATA_STAIRS),
+    BlockInfo::new(157, "activator_rail", "Activator Rail", DATA_FACING_POWERED),
+    BlockInfo::new(158, "dropper", "Dropper", DATA_FACING_POWERED),
+    BlockInfo::new(159, "stained_hardened_clay", "Stained Clay", DATA_ALL),
+    BlockInfo::new(160, "stained_glass_pane", "Stained Glass Pane", DATA_ALL),
+    BlockInfo::new(
+        161,
+        "leaves2",
+        "Leaves (Acacia/Dark Oak)",
+        data_values(0, 1) | data_values(4, 5) | data_values(8, 9) | data_values(12, 13),
+    ),
+    BlockInfo::new(
+        162,
+        "log2",
+        "Wood (Acacia/Dark Oak)",
+        data_values(0, 1) | data_values(4, 5) | data_values(8, 9) | data_values(12, 13),
+    ),
+    BlockInfo::new(163, "acacia_stairs", "Acacia Wood Stairs", DATA_STAIRS),
+    BlockInfo::new(164, "dark_oak_stairs", "Dark Oak Wood Stairs", DATA_STAIRS),
+    BlockInfo::new(165, "slime", "Slime Block", DATA_NONE),
+    BlockInfo::new(166, "barrier", "Barrier", DATA_NONE),
+    BlockInfo::new(167, "iron_trapdoor", "Iron Trapdoor", DATA_ALL),
+    BlockInfo::new(168, "prismarine", "Prismarine", data_values(0, 2)),
+    BlockInfo::new(169, "sea_lantern", "Sea Lantern", DATA_NONE),
+    BlockInfo::new(170, "hay_block", "Hay Bale", DATA_AXIS),
+    BlockInfo::new(171, "carpet", "Carpet", DATA_ALL),
+    BlockInfo::new(172, "hardened_clay", "Hardened Clay", DATA_NONE),
+    BlockInfo::new(173, "coal_block", "Block of Coal", DATA_NONE),
+    BlockInfo::new(174, "packed_ice", "Packed Ice", DATA_NONE),
+    BlockInfo::new(
+        175,
+        "double_plant",
+        "Large Flowers",
+        data_values(0, 5) | data_values(8, 11),
+    ),
+    BlockInfo::new(176, "standing_banner", "Banner", DATA_ALL),
+    BlockInfo::new(177, "wall_banner", "Wall Banner", DATA_HORIZONTAL),
+    BlockInfo::new(
+        178,
+        "daylight_detector_inverted",
+        "Inverted Daylight Sensor",
+        DATA_ALL,
+    ),
+    BlockInfo::new(179, "red_sandstone", "Red Sandstone", data_values(0, 2)),
+    BlockInfo::new(
+        180,
+        "red_sandstone_stairs",
+        "Red Sandstone Stairs",
+        DATA_STAIRS,
+    ),
+    BlockInfo::new(
+        181,
+        "double_stone_slab2",
+        "Double Red Sandstone Slab",
+        data_values(0, 0) | data_values(8, 8),
+    ),
+    BlockInfo::new(
+        182,
+        "stone_slab2",
+        "Red Sandstone Slab",
+        data_values(0, 0) | data_values(8, 8),
+    ),
+    BlockInfo::new(183, "spruce_fence_gate", "Spruce Fence Gate", DATA_ALL),
+    BlockInfo::new(184, "birch_fence_gate", "Birch Fence Gate", DATA_ALL),
+    BlockInfo::new(185, "jungle_fence_gate", "Jungle Fence Gate", DATA_ALL),
+    BlockInfo::new(186, "dark_oak_fence_gate", "Dark Oak Fence Gate", DATA_ALL),
+    BlockInfo::new(187, "acacia_fence_gate", "Acacia Fence Gate", DATA_ALL),
+    BlockInfo::new(188, "spruce_fence", "Spruce Fence", DATA_NONE),
+    BlockInfo::new(189, "birch_fence", "Birch Fence", DATA_NONE),
+    BlockInfo::new(190, "jungle_fence", "Jungle Fence", DATA_NONE),
+    BlockInfo::new(191, "dark_oak_fence", "Dark Oak Fence", DATA_NONE),
+    BlockInfo::new(192, "acacia_fence", "Acacia Fence", DATA_NONE),
+    BlockInfo::new(193, "spruce_door", "Spruce Door", DATA_DOOR),
+    BlockInfo::new(194, "birch_door", "Birch Door", DATA_DOOR),
+    BlockInfo::new(195, "jungle_door", "Jungle Door", DATA_DOOR),
+    BlockInfo::new(196, "acacia_door", "Acacia Door", DATA_DOOR),
+    BlockInfo::new(197, "dark_oak_door", "Dark Oak Door", DATA_DOOR),
+    BlockInfo::new(198, "end_rod", "End Rod", DATA_FACING),
+    BlockInfo::new(199, "chorus_plant", "Chorus Plant", DATA_NONE),
+    BlockInfo::new(200, "chorus_flower", "Chorus Flower", data_values(0, 5)),
+    BlockInfo::new(201, "purpur_block", "Purpur Block", DATA_NONE),
+    BlockInfo::new(202, "purpur_pillar", "Purpur Pillar", DATA_AXIS),
+    BlockInfo::new(203, "purpur_stairs", "Purpur Stairs", DATA_STAIRS),
+    BlockInfo::new(204, "purpur_double_slab", "Purpur Double Slab", DATA_NONE),
+    BlockInfo::new(
+        205,
+        "purpur_slab",
+        "Purpur Slab",
+        data_values(0, 0) | data_values(8, 8),
+    ),
+    BlockInfo::new(206, "end_bricks", "End Stone Bricks", DATA_NONE),
+    BlockInfo::new(207, "beetroots", "Beetroots", data_values(0, 3)),
+    BlockInfo::new(208, "grass_path", "Grass Path", DATA_NONE),
+    BlockInfo::new(209, "end_gateway", "End Gateway", DATA_NONE),
+    BlockInfo::new(
+        210,
+        "repeating_command_block",
+        "Repeating Command Block",
+        DATA_FACING_POWERED,
+    ),
+    BlockInfo::new(
+        211,
+        "chain_command_block",
+        "Chain Command Block",
+        DATA_FACING_POWERED,
+    ),
+    BlockInfo::new(212, "frosted_ice", "Frosted Ice", data_values(0, 3)),
+    BlockInfo::new(213, "magma", "Magma Block", DATA_NONE),
+    BlockInfo::new(214, "nether_wart_block", "Nether Wart Block", DATA_NONE),
+    BlockInfo::new(215, "red_nether_brick", "Red Nether Brick", DATA_NONE),
+    BlockInfo::new(216, "bone_block", "Bone Block", DATA_AXIS),
+    BlockInfo::new(217, "structure_void", "Structure Void", DATA_NONE),
+    BlockInfo::new(218, "observer", "Observer", DATA_FACING_POWERED),
+    BlockInfo::new(219, "white_shulker_box", "White Shulker Box", DATA_FACING),
+    BlockInfo::new(220, "orange_shulker_box", "Orange Shulker Box", DATA_FACING),
+    BlockInfo::new(
+        221,
+        "magenta_shulker_box",
+        "Magenta Shulker Box",
+        DATA_FACING,
+    ),
+    BlockInfo::new(
+        222,
+        "light_blue_shulker_box",
+        "Light Blue Shulker Box",
+        DATA_FACING,
+    ),
+    BlockInfo::new(223, "yellow_shulker_box", "Yellow Shulker Box", DATA_FACING),
+    BlockInfo::new(224, "lime_shulker_box", "Lime Shulker Box", DATA_FACING),
+    BlockInfo::new(225, "pink_shulker_box", "Pink Shulker Box", DATA_FACING),
+    BlockInfo::new(226, "gray_shulker_box", "Gray Shulker Box", DATA_FACING),
+    BlockInfo::new(
+        227,
+        "silver_shulker_box",
+        "Light Gray Shulker Box",
+        DATA_FACING,
+    ),
+    BlockInfo::new(228, "cyan_shulker_box", "Cyan Shulker Box", DATA_FACING),
+    BlockInfo::new(229, "purple_shulker_box", "Purple Shulker Box", DATA_FACING),
+    BlockInfo::new(230, "blue_shulker_box", "Blue Shulker Box", DATA_FACING),
+    BlockInfo::new(231, "brown_shulker_box", "Brown Shulker Box", DATA_FACING),
+    BlockInfo::new(232, "green_shulker_box", "Green Shulker Box", DATA_FACING),
+    BlockInfo::new(233, "red_shulker_box", "Red Shulker Box", DATA_FACING),
+    BlockInfo::new(234, "black_shulker_box", "Black Shulker Box", DATA_FACING),
+    BlockInfo::new(
+        235,
+        "white_glazed_terracotta",
+        "White Glazed Terracotta",
+        data_values(0, 3),
+    ),
+    BlockInfo::new(
+        236,
+        "orange_glazed_terracotta",
+        "Orange Glazed Terracotta",
+        data_values(0, 3),
+    ),
+    BlockInfo::new(
+        237,
+        "magenta_glazed_terracotta",
+        "Magenta Glazed Terracotta",
+        data_values(0, 3),
+    ),
+    BlockInfo::new(
+        238,
+        "light_blue_glazed_terracotta",
+        "Light Blue Glazed Terracotta",
+        data_values(0, 3),
+    ),
+    BlockInfo::new(
+        239,
+        "yellow_glazed_terracotta",
+        "Yellow Glazed Terracotta",
+        data_values(0, 3),
+    ),
+    BlockInfo::new(
+        240,
+        "lime_glazed_terracotta",
+        "Lime Glazed Terracotta",
+        data_values(0, 3),
+    ),
+    BlockInfo::new(
+        241,
+        "pink_glazed_terracotta",
+        "Pink Glazed Terracotta",
+        data_values(0, 3),
+    ),
+    BlockInfo::new(
+        242,
+        "gray_glazed_terracotta",
+        "Gray Glazed Terracotta",
+        data_values(0, 3),
+    ),
+    BlockInfo::new(
+        243,
+        "silver_glazed_terracotta",
+        "Light Gray Glazed Terracotta",
+        data_values(0, 3),
+    ),
+    BlockInfo::new(
+        244,
+        "cyan_glazed_terracotta",
+        "Cyan Glazed Terracotta",
+        data_values(0, 3),
+    ),
+    BlockInfo::new(
+        245,
+        "purple_glazed_terracotta",
+        "Purple Glazed Terracotta",
+        data_values(0, 3),
+    ),
+    BlockInfo::new(
+        246,
+        "blue_glazed_terracotta",
+        "Blue Glazed Terracotta",
+        data_values(0, 3),
+    ),
+    BlockInfo::new(
+        247,
+        "brown_glazed_terracotta",
+        "Brown Glazed Terracotta",
+        data_values(0, 3),
+    ),
+    BlockInfo::new(
+        248,
+        "green_glazed_terracotta",
+        "Green Glazed Terracotta",
+        data_values(0, 3),
+    ),
+    BlockInfo::new(
+        249,
+        "red_glazed_terracotta",
+        "Red Glazed Terracotta",
+        data_values(0, 3),
+    ),
+    BlockInfo::new(
+        250,
+        "black_glazed_terracotta",
+        "Black Glazed Terracotta",
+        data_values(0, 3),
+    ),
+    BlockInfo::new(251, "concrete", "Concrete", DATA_ALL),
+    BlockInfo::new(252, "concrete_powder", "Concrete Powder", DATA_ALL),
+    BlockInfo::new(255, "structure_block", "Structure Block", data_values(0, 3)),
+];
+/// the lowercase constant names
+///
+/// a canonical name wins over an alias, so `"snow"` is [`SNOW_BLOCK`] (80), not [`SNOW`] (78)
+#[allow(deprecated)]
+static ALIASES: &[(&str, Item)] = &[
+    ("air", AIR),
+    ("stone", STONE),
+    ("grass", GRASS),
+    ("dirt", DIRT),
+    ("cobblestone", COBBLESTONE),
+    ("wood_planks", WOOD_PLANKS),
+    ("sapling", SAPLING),
+    ("bedrock", BEDROCK),
+    ("water_flowing", WATER_FLOWING),
+    ("water", WATER),
+    ("water_stationary", WATER_STATIONARY),
+    ("lava_flowing", LAVA_FLOWING),
+    ("lava", LAVA),
+    ("lava_stationary", LAVA_STATIONARY),
+    ("sand", SAND),
+    ("gravel", GRAVEL),
+    ("gold_ore", GOLD_ORE),
+    ("iron_ore", IRON_ORE),
+    ("coal_ore", COAL_ORE),
+    ("wood", WOOD),
+    ("leaves", LEAVES),
+    ("sponge", SPONGE),
+    ("glass", GLASS),
+    ("lapis_lazuli_ore", LAPIS_LAZULI_ORE),
+    ("lapis_lazuli_block", LAPIS_LAZULI_BLOCK),
+    ("lapins_lazuli_ore", LAPINS_LAZULI_ORE),
+    ("lapins_lazuli_block", LAPINS_LAZULI_BLOCK),
+    ("dispenser", DISPENSER),
+    ("sandstone", SANDSTONE),
+    ("note_block", NOTE_BLOCK),
+    ("bed", BED),
+    ("rail_powered", RAIL_POWERED),
+    ("rail_detector", RAIL_DETECTOR),
+    ("piston_sticky", PISTON_STICKY),
+    ("cobweb", COBWEB),
+    ("grass_tall", GRASS_TALL),
+    ("dead_bush", DEAD_BUSH),
+    ("piston", PISTON),
+    ("piston_head", PISTON_HEAD),
+    ("wool", WOOL),
+    ("piston_moving", PISTON_MOVING),
+    ("flower_yellow", FLOWER_YELLOW),
+    ("flower_cyan", FLOWER_CYAN),
+    ("flower_red", FLOWER_RED),
+    ("mushroom_brown", MUSHROOM_BROWN),
+    ("mushroom_red", MUSHROOM_RED),
+    ("gold_block", GOLD_BLOCK),
+    ("iron_block", IRON_BLOCK),
+    ("stone_slab_double", STONE_SLAB_DOUBLE),
+    ("stone_slab", STONE_SLAB),
+    ("brick_block", BRICK_BLOCK),
+    ("tnt", TNT),
+    ("bookshelf", BOOKSHELF),
+    ("moss_stone", MOSS_STONE),
+    ("obsidian", OBSIDIAN),
+    ("torch", TORCH),
+    ("fire", FIRE),
+    ("mob_spawner", MOB_SPAWNER),
+    ("stairs_wood", STAIRS_WOOD),
+    ("chest", CHEST),
+    ("redstone_wire", REDSTONE_WIRE),
+    ("diamond_ore", DIAMOND_ORE),
+    ("diamond_block", DIAMOND_BLOCK),
+    ("crafting_table", CRAFTING_TABLE),
+    ("wheat", WHEAT),
+    ("farmland", FARMLAND),
+    ("furnace_inactive", FURNACE_INACTIVE),
+    ("furnace_active", FURNACE_ACTIVE),
+    ("sign_post", SIGN_POST),
+    ("door_wood", DOOR_WOOD),
+    ("ladder", LADDER),
+    ("rail", RAIL),
+    ("stairs_cobblestone", STAIRS_COBBLESTONE),
+    ("sign_wall", SIGN_WALL),
+    ("lever", LEVER),
+    ("pressure_plate_stone", PRESSURE_PLATE_STONE),
+    ("door_iron", DOOR_IRON),
+    ("pressure_plate_wood", PRESSURE_PLATE_WOOD),
+    ("redstone_ore", REDSTONE_ORE),
+    ("redstone_ore_glowing", REDSTONE_ORE_GLOWING),
+    ("redstone_torch_off", REDSTONE_TORCH_OFF),
+    ("redstone_torch", REDSTONE_TORCH),
+    ("button_stone", BUTTON_STONE),
+    ("snow", SNOW),
+    ("ice", ICE),
+    ("snow_block", SNOW_BLOCK),
+    ("cactus", CACTUS),
+    ("clay", CLAY),
+    ("sugar_cane", SUGAR_CANE),
+    ("jukebox", JUKEBOX),
+    ("fence", FENCE),
+    ("pumpkin", PUMPKIN),
+    ("netherrack", NETHERRACK),
+    ("soul_sand", SOUL_SAND),
+    ("glowstone_block", GLOWSTONE_BLOCK),
+    ("portal", PORTAL),
+    ("jack_o_lantern", JACK_O_LANTERN),
+    ("cake", CAKE),
+    ("repeater", REPEATER),
+    ("repeater_powered", REPEATER_POWERED),
+    ("stained_glass", STAINED_GLASS),
+    ("trapdoor_wood", TRAPDOOR_WOOD),
+    ("monster_egg", MONSTER_EGG),
+    ("stone_brick", STONE_BRICK),
+    ("mushroom_block_brown", MUSHROOM_BLOCK_BROWN),
+    ("mushroom_block_red", MUSHROOM_BLOCK_RED),
+    ("iron_bars", IRON_BARS),
+    ("glass_pane", GLASS_PANE),
+    ("melon", MELON),
+    ("pumpkin_stem", PUMPKIN_STEM),
+    ("melon_stem", MELON_STEM),
+    ("vine", VINE),
+    ("fence_gate", FENCE_GATE),
+    ("stairs_brick", STAIRS_BRICK),
+    ("stairs_stone_brick", STAIRS_STONE_BRICK),
+    ("mycelium", MYCELIUM),
+    ("lily_pad", LILY_PAD),
+    ("nether_brick", NETHER_BRICK),
+    ("fence_nether_brick", FENCE_NETHER_BRICK),
+    ("stairs_nether_brick", STAIRS_NETHER_BRICK),
+    ("nether_wart", NETHER_WART),
+    ("enchanting_table", ENCHANTING_TABLE),
+    ("brewing_stand", BREWING_STAND),
+    ("cauldron", CAULDRON),
+    ("end_portal", END_PORTAL),
+    ("end_portal_frame", END_PORTAL_FRAME),
+    ("end_stone", END_STONE),
+    ("dragon_egg", DRAGON_EGG),
+    ("redstone_lamp", REDSTONE_LAMP),
+    ("redstone_lamp_on", REDSTONE_LAMP_ON),
+    ("wood_slab_double", WOOD_SLAB_DOUBLE),
+    ("wood_slab", WOOD_SLAB),
+    ("cocoa", COCOA),
+    ("stairs_sandstone", STAIRS_SANDSTONE),
+    ("emerald_ore", EMERALD_ORE),
+    ("ender_chest", ENDER_CHEST),
+    ("tripwire_hook", TRIPWIRE_HOOK),
+    ("tripwire", TRIPWIRE),
+    ("emerald_block", EMERALD_BLOCK),
+    ("stairs_spruce", STAIRS_SPRUCE),
+    ("stairs_birch", STAIRS_BIRCH),
+    ("stairs_jungle", STAIRS_JUNGLE),
+    ("command_block", COMMAND_BLOCK),
+    ("beacon", BEACON),
+    ("cobblestone_wall", COBBLESTONE_WALL),
+    ("flower_pot", FLOWER_POT),
+    ("carrots", CARROTS),
+    ("potatoes", POTATOES),
+    ("button_wood", BUTTON_WOOD),
+    ("skull", SKULL),
+    ("anvil", ANVIL),
+    ("chest_trapped", CHEST_TRAPPED),
+    ("pressure_plate_gold", PRESSURE_PLATE_GOLD),
+    ("pressure_plate_iron", PRESSURE_PLATE_IRON),
+    ("comparator", COMPARATOR),
+    ("comparator_powered", COMPARATOR_POWERED),
+    ("daylight_detector", DAYLIGHT_DETECTOR),
+    ("redstone_block", REDSTONE_BLOCK),
+    ("quartz_ore", QUARTZ_ORE),
+    ("hopper", HOPPER),
+    ("quartz_block", QUARTZ_BLOCK),
+    ("stairs_quartz", STAIRS_QUARTZ),
+    ("rail_activator", RAIL_ACTIVATOR),
+    ("dropper", DROPPER),
+    ("stained_clay", STAINED_CLAY),
+    ("stained_glass_pane", STAINED_GLASS_PANE),
+    ("leaves2", LEAVES2),
+    ("wood2", WOOD2),
+    ("stairs_acacia", STAIRS_ACACIA),
+    ("stairs_dark_oak", STAIRS_DARK_OAK),
+    ("slime_block", SLIME_BLOCK),
+    ("barrier", BARRIER),
+    ("trapdoor_iron", TRAPDOOR_IRON),
+    ("prismarine", PRISMARINE),
+    ("sea_lantern", SEA_LANTERN),
+    ("hay_block", HAY_BLOCK),
+    ("carpet", CARPET),
+    ("hardened_clay", HARDENED_CLAY),
+    ("coal_block", COAL_BLOCK),
+    ("packed_ice", PACKED_ICE),
+    ("double_plant", DOUBLE_PLANT),
+    ("banner_standing", BANNER_STANDING),
+    ("banner_wall", BANNER_WALL),
+    ("daylight_detector_inverted", DAYLIGHT_DETECTOR_INVERTED),
+    ("red_sandstone", RED_SANDSTONE),
+    ("stairs_red_sandstone", STAIRS_RED_SANDSTONE),
+    ("stone_slab2_double", STONE_SLAB2_DOUBLE),
+    ("stone_slab2", STONE_SLAB2),
+    ("fence_gate_spruce", FENCE_GATE_SPRUCE),
+    ("fence_gate_birch", FENCE_GATE_BIRCH),
+    ("fence_gate_jungle", FENCE_GATE_JUNGLE),
+    ("fence_gate_dark_oak", FENCE_GATE_DARK_OAK),
+    ("fence_gate_acacia", FENCE_GATE_ACACIA),
+    ("fence_spruce", FENCE_SPRUCE),
+    ("fence_birch", FENCE_BIRCH),
+    ("fence_jungle", FENCE_JUNGLE),
+    ("fence_dark_oak", FENCE_DARK_OAK),
+    ("fence_acacia", FENCE_ACACIA),
+    ("door_spruce", DOOR_SPRUCE),
+    ("door_birch", DOOR_BIRCH),
+    ("door_jungle", DOOR_JUNGLE),
+    ("door_acacia", DOOR_ACACIA),
+    ("door_dark_oak", DOOR_DARK_OAK),
+    ("end_rod", END_ROD),
+    ("chorus_plant", CHORUS_PLANT),
+    ("chorus_flower", CHORUS_FLOWER),
+    ("purpur_block", PURPUR_BLOCK),
+    ("purpur_pillar", PURPUR_PILLAR),
+    ("stairs_purpur", STAIRS_PURPUR),
+    ("purpur_slab_double", PURPUR_SLAB_DOUBLE),
+    ("purpur_slab", PURPUR_SLAB),
+    ("end_bricks", END_BRICKS),
+    ("beetroots", BEETROOTS),
+    ("grass_path", GRASS_PATH),
+    ("end_gateway", END_GATEWAY),
+    ("command_block_repeating", COMMAND_BLOCK_REPEATING),
+    ("command_block_chain", COMMAND_BLOCK_CHAIN),
+    ("frosted_ice", FROSTED_ICE),
+    ("magma_block", MAGMA_BLOCK),
+    ("nether_wart_block", NETHER_WART_BLOCK),
+    ("red_nether_brick", RED_NETHER_BRICK),
+    ("bone_block", BONE_BLOCK),
+    ("structure_void", STRUCTURE_VOID),
+    ("observer", OBSERVER),
+    ("shulker_box_white", SHULKER_BOX_WHITE),
+    ("shulker_box_orange", SHULKER_BOX_ORANGE),
+    ("shulker_box_magenta", SHULKER_BOX_MAGENTA),
+    ("shulker_box_light_blue", SHULKER_BOX_LIGHT_BLUE),
+    ("shulker_box_yellow", SHULKER_BOX_YELLOW),
+    ("shulker_box_lime", SHULKER_BOX_LIME),
+    ("shulker_box_pink", SHULKER_BOX_PINK),
+    ("shulker_box_gray", SHULKER_BOX_GRAY),
+    ("shulker_box_light_gray", SHULKER_BOX_LIGHT_GRAY),
+    ("shulker_box_cyan", SHULKER_BOX_CYAN),
+    ("shulker_box_purple", SHULKER_BOX_PURPLE),
+    ("shulker_box_blue", SHULKER_BOX_BLUE),
+    ("shulker_box_brown", SHULKER_BOX_BROWN),
+    ("shulker_box_green", SHULKER_BOX_GREEN),
+    ("shulker_box_red", SHULKER_BOX_RED),
+    ("shulker_box_black", SHULKER_BOX_BLACK),
+    ("glazed_terracotta_white", GLAZED_TERRACOTTA_WHITE),
+    ("glazed_terracotta_orange", GLAZED_TERRACOTTA_ORANGE),
+    ("glazed_terracotta_magenta", GLAZED_TERRACOTTA_MAGENTA),
+    ("glazed_terracotta_light_blue", GLAZED_TERRACOTTA_LIGHT_BLUE),
+    ("glazed_terracotta_yellow", GLAZED_TERRACOTTA_YELLOW),
+    ("glazed_terracotta_lime", GLAZED_TERRACOTTA_LIME),
+    ("glazed_terracotta_pink", GLAZED_TERRACOTTA_PINK),
+    ("glazed_terracotta_gray", GLAZED_TERRACOTTA_GRAY),
+    ("glazed_terracotta_light_gray", GLAZED_TERRACOTTA_LIGHT_GRAY),
+    ("glazed_terracotta_cyan", GLAZED_TERRACOTTA_CYAN),
+    ("glazed_terracotta_purple", GLAZED_TERRACOTTA_PURPLE),
+    ("glazed_terracotta_blue", GLAZED_TERRACOTTA_BLUE),
+    ("glazed_terracotta_brown", GLAZED_TERRACOTTA_BROWN),
+    ("glazed_terracotta_green", GLAZED_TERRACOTTA_GREEN),
+    ("glazed_terracotta_red", GLAZED_TERRACOTTA_RED),
+    ("glazed_terracotta_black", GLAZED_TERRACOTTA_BLACK),
+    ("concrete", CONCRETE),
+    ("concrete_powder", CONCRETE_POWDER),
+    ("structure_block", STRUCTURE_BLOCK),
+    ("bedrock_invisible", BEDROCK_INVISIBLE),
+    ("glowing_obsidian", GLOWING_OBSIDIAN),
+    ("nether_reactor_core", NETHER_REACTOR_CORE),
+];
+fn alias(name: &str) -> Option<Item> {
+    ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == name)
+        .map(|(_, item)| item.clone())
+}
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Block {
     id: Item,
+    #[cfg_attr(feature = "serde", serde(default))]
     data: i32,
 }
 impl Block {
@@ -97,21 +1253,40 @@ impl Block {
     pub fn from_item(id: Item) -> Block {
         Block { id, data: 0 }
     }
+    /// get the item of the block
+    pub fn item(&self) -> Item {
+        self.id.clone()
+    }
+    /// get the data value of the block
+    pub fn data(&self) -> i32 {
+        self.data
+    }
+    /// convert a `id` or `id,data` string to a block
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(Block::decode(String::from("35,14\n")), Block::new(14, WOOL));
+    /// assert_eq!(Block::decode(String::from("1\n")), Block::from_item(STONE));
+    /// ```
     pub fn decode(s: String) -> Block {
-        return if !s.contains(',') {
-            Block::new_without_data(Id(s.split_whitespace().collect::<Vec<&str>>()[0]
-                .parse::<u32>()
-                .expect("Failed to parse")))
+        let s = s.trim();
+        if !s.contains(',') {
+            Block::new_without_data(Id(s.parse::<u32>().expect("Failed to parse")))
         } else {
             let vec = s.split(',').collect::<Vec<&str>>();
             let id = Id(vec[0].parse::<u32>().expect("Failed to parse"));
             let data = vec[1].parse::<i32>().expect("Failed to parse");
             Block::new(data, id)
-        };
+        }
     }
 }
-impl ToString for Block {
-    fn to_string(&self) -> String {
-        format!("{}", self.clone().id.unwrap())
+/// the block as sent to the server (`id,data`)
+///
+/// `world.setBlock` and `world.setBlocks` send the data value too, so typed variants (wool
+/// colors, stairs facing...) are placed as they are and not as the data value 0
+impl Display for Block {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.clone().id.unwrap(), self.data)
     }
 }
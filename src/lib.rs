@@ -1,5 +1,20 @@
+pub mod camera;
+pub mod commands;
 pub mod connection;
+pub mod entities;
+pub mod error;
+pub mod event_loop;
 pub mod events;
+mod interop;
 pub mod items;
 pub mod minecraft;
+pub mod position;
 pub mod prelude;
+pub mod properties;
+pub mod region;
+pub mod settings;
+pub mod shapes;
+pub mod sign;
+pub mod states;
+pub mod variants;
+pub mod watchers;
@@ -28,32 +28,136 @@
 //! mc.mc_post_to_chat("Hello World!");
 //! ```
 
+use crate::camera::CameraMode;
 use crate::connection::Connection;
-use crate::items::Block;
-use std::fmt::Display;
+use crate::entities::{Entity, EntityType};
+use crate::error::Error;
+use crate::events::{BlockEvent, ChatEvent, Event, ProjectileEvent};
+use crate::items::{Block, Item::Id, AIR};
+use crate::position::{Axis, BlockPos, Vec3};
+use crate::properties::sort_placements;
+use crate::region::{Region, MAX_SET_BLOCKS_VOLUME};
+use crate::settings::{PlayerSetting, WorldSetting};
+use crate::shapes::{self, ArchStyle, Half};
+use crate::sign::Sign;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
 use std::net::{IpAddr, Ipv4Addr, SocketAddr, ToSocketAddrs};
+use std::sync::{Arc, Mutex};
 pub const DEFAULT_PORT: u16 = 4711;
 
+/// the flavour of the api spoken by the server
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Dialect {
+    /// the Bukkit plugin RaspberryJuice (the default)
+    RaspberryJuice,
+    /// Minecraft: Pi Edition
+    PiEdition,
+}
+impl Display for Dialect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Dialect::RaspberryJuice => write!(f, "RaspberryJuice"),
+            Dialect::PiEdition => write!(f, "Minecraft: Pi Edition"),
+        }
+    }
+}
+/// how the positions used by the server relate to the world coordinates
+///
+/// Minecraft: Pi Edition and RaspberryJuice (unless `location: ABSOLUTE` is configured)
+/// report every position relative to the spawn point of the world.
+/// a `Minecraft` with a `SpawnRelative` frame converts every position it sends and receives,
+/// so the positions of the api are always world coordinates
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let frame = CoordinateFrame::SpawnRelative { spawn: BlockPos::new(100, 64, -20) };
+/// assert_eq!(frame.to_server_int((110, 70, -20)), BlockPos::new(10, 6, 0));
+/// assert_eq!(frame.from_server((0.5, 0.0, 0.5)), Vec3::new(100.5, 64.0, -19.5));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CoordinateFrame {
+    /// the server uses world coordinates, nothing is converted (the default)
+    Absolute,
+    /// the server uses positions relative to the spawn point at `spawn` (in world coordinates)
+    SpawnRelative { spawn: BlockPos },
+}
+impl CoordinateFrame {
+    /// construct the frame of a server from a position it reported
+    /// and the world coordinates of the same place (such as the ones shown by F3)
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// // the server says the player stands on (3, 0, -1), F3 says (103, 64, -21)
+    /// let frame = CoordinateFrame::from_known_position((3, 0, -1), (103, 64, -21));
+    /// assert_eq!(frame.spawn_offset(), BlockPos::new(100, 64, -20));
+    /// ```
+    pub fn from_known_position(
+        reported: impl Into<BlockPos>,
+        absolute: impl Into<BlockPos>,
+    ) -> CoordinateFrame {
+        let spawn = absolute.into() - reported.into();
+        if spawn == BlockPos::ORIGIN {
+            CoordinateFrame::Absolute
+        } else {
+            CoordinateFrame::SpawnRelative { spawn }
+        }
+    }
+    /// get the world coordinates of the origin of the server positions
+    pub fn spawn_offset(&self) -> BlockPos {
+        match self {
+            CoordinateFrame::Absolute => BlockPos::ORIGIN,
+            CoordinateFrame::SpawnRelative { spawn } => *spawn,
+        }
+    }
+    /// convert world coordinates to a server position
+    pub fn to_server_int(&self, pos: impl Into<BlockPos>) -> BlockPos {
+        pos.into() - self.spawn_offset()
+    }
+    /// convert a server position to world coordinates
+    pub fn from_server_int(&self, pos: impl Into<BlockPos>) -> BlockPos {
+        pos.into() + self.spawn_offset()
+    }
+    /// convert world coordinates to a server position
+    pub fn to_server(&self, pos: impl Into<Vec3>) -> Vec3 {
+        pos.into() - self.spawn_offset().to_vec3()
+    }
+    /// convert a server position to world coordinates
+    pub fn from_server(&self, pos: impl Into<Vec3>) -> Vec3 {
+        pos.into() + self.spawn_offset().to_vec3()
+    }
+}
+/// the blocks overwritten inside an emulated checkpoint, by position: the block before the
+/// checkpoint and the last block written
+type Journal = Arc<Mutex<BTreeMap<BlockPos, (Block, Block)>>>;
 /// The Minecraft type
 #[derive(Clone)]
 pub struct Minecraft {
     connection: Connection,
+    dialect: Dialect,
+    frame: CoordinateFrame,
+    journal: Option<Journal>,
 }
-/// convert a string to (f64,f64,f64)
+/// convert a string to a `Vec3`
 /// # Examples
 /// ```
 /// use mcpi_rs::minecraft::pos_decode;
 ///
 /// let a = String::from("1.0,2.0,3.0");
-/// assert_eq!(pos_decode(a).0,1.0);
+/// assert_eq!(pos_decode(a).x,1.0);
 ///
 /// let b = String::from("1.1,2.5,3.4");
-/// assert_eq!(pos_decode(b).1,2.5);
+/// assert_eq!(pos_decode(b).y,2.5);
 /// ```
-pub fn pos_decode(pos_str: impl ToString) -> (f64, f64, f64) {
+pub fn pos_decode(pos_str: impl ToString) -> Vec3 {
     let s = pos_str.to_string();
     let vec = s.split(',').collect::<Vec<&str>>();
-    (
+    Vec3::new(
         vec[0].parse().expect("Failed to parse1"),
         vec[1].parse().expect("Failed to parse2"),
         vec[2].split_whitespace().collect::<Vec<&str>>()[0]
@@ -61,24 +165,25 @@ pub fn pos_decode(pos_str: impl ToString) -> (f64, f64, f64) {
             .expect("Failed to parse3"),
     )
 }
-/// convert a string to (i32,i32,i32)
+/// convert a string to a `BlockPos`
 ///
-/// if you want to convert to (f64.f64,f64),please use `pos_decode`
+/// if you want to convert to a `Vec3`,please use `pos_decode`
 ///
 /// # Examples
 /// ```
 /// use mcpi_rs::minecraft::pos_decode_int;
+/// use mcpi_rs::position::BlockPos;
 ///
 /// let a = String::from("1,2,3");
-/// assert_eq!(pos_decode_int(a),(1,2,3));
+/// assert_eq!(pos_decode_int(a),BlockPos::new(1,2,3));
 ///
 /// let b = String::from("3,5,4");
-/// assert_eq!(pos_decode_int(b).1,3);
+/// assert_eq!(pos_decode_int(b).y,5);
 /// ```
-pub fn pos_decode_int(pos_str: impl ToString) -> (i32, i32, i32) {
+pub fn pos_decode_int(pos_str: impl ToString) -> BlockPos {
     let s = pos_str.to_string().replace("\n", "");
     let vec = s.split(',').collect::<Vec<&str>>();
-    (
+    BlockPos::new(
         vec[0].parse().expect("Failed to parse1"),
         vec[1].parse().expect("Failed to parse 2"),
         vec[2].parse().expect("Failed to parse 3"),
@@ -94,6 +199,24 @@ pub fn pos_decode_int(pos_str: impl ToString) -> (i32, i32, i32) {
 pub fn pos_to_string(pos: (impl Display, impl Display, impl Display)) -> String {
     format!("{},{},{}", pos.0, pos.1, pos.2)
 }
+/// get the positions of the cuboid between two corners (inclusive)
+/// in the order of `world.getBlocks` (y, then x, then z), see `Region::positions`
+/// # Example
+/// ```
+/// use mcpi_rs::minecraft::cuboid_positions;
+/// use mcpi_rs::position::BlockPos;
+///
+/// assert_eq!(
+///     cuboid_positions((1, 0, 1), (0, 1, 0))[..3],
+///     [BlockPos::new(0, 0, 0), BlockPos::new(0, 0, 1), BlockPos::new(1, 0, 0)]
+/// );
+/// ```
+pub fn cuboid_positions(
+    corner1: impl Into<BlockPos>,
+    corner2: impl Into<BlockPos>,
+) -> Vec<BlockPos> {
+    Region::new(corner1, corner2).positions().collect()
+}
 
 /////////////////////////////////////////////////////////////////////////////
 // Type implementation
@@ -109,7 +232,53 @@ impl Minecraft {
     /// let mc = Minecraft::new(SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127,0,0,1)),4173));
     /// ```
     pub fn new(connection: Connection) -> Minecraft {
-        Minecraft { connection }
+        Minecraft {
+            connection,
+            dialect: Dialect::RaspberryJuice,
+            frame: CoordinateFrame::Absolute,
+            journal: None,
+        }
+    }
+    /// set the dialect of the server (RaspberryJuice by default)
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect().with_dialect(Dialect::PiEdition);
+    /// ```
+    pub fn with_dialect(mut self, dialect: Dialect) -> Minecraft {
+        self.dialect = dialect;
+        self
+    }
+    /// get the dialect of the server
+    pub fn dialect(&self) -> Dialect {
+        self.dialect
+    }
+    /// set how the positions of the server relate to the world coordinates
+    /// (`CoordinateFrame::Absolute` by default)
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect()
+    ///     .with_frame(CoordinateFrame::SpawnRelative { spawn: BlockPos::new(100, 64, -20) });
+    ///
+    /// // places the block at (100, 64, -20) in world coordinates,
+    /// // which the server calls (0, 0, 0)
+    /// mc.mc_set_block((100, 64, -20), Block::from_item(GOLD_BLOCK));
+    /// ```
+    pub fn with_frame(mut self, frame: CoordinateFrame) -> Minecraft {
+        self.frame = frame;
+        self
+    }
+    /// get how the positions of the server relate to the world coordinates
+    pub fn frame(&self) -> CoordinateFrame {
+        self.frame
+    }
+    /// get the world coordinates of the origin of the server positions
+    /// ((0,0,0) if the server uses world coordinates)
+    pub fn spawn_offset(&self) -> BlockPos {
+        self.frame.spawn_offset()
     }
     /// set a connection between minecraft and rust (default port)
     /// # Example
@@ -148,10 +317,27 @@ impl Minecraft {
     ///
     /// assert_eq!(block,Block::from_item(STONE))
     /// ```
-    pub fn mc_get_block(self, pos: (i32, i32, i32)) -> Block {
+    pub fn mc_get_block(self, pos: impl Into<BlockPos>) -> Block {
         self.clone()
             .connection
-            .send(vec!["world.getBlock", pos_to_string(pos).as_str()]);
+            .send(vec!["world.getBlock", self.server_pos_int(pos).as_str()]);
+        Block::decode(self.connection.receive())
+    }
+    /// get a block and its data value from minecraft
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// mc.clone().mc_set_block((1, 2, 3), Block::new(14, WOOL));
+    /// assert_eq!(mc.mc_get_block_with_data((1, 2, 3)), Block::new(14, WOOL));
+    /// ```
+    pub fn mc_get_block_with_data(self, pos: impl Into<BlockPos>) -> Block {
+        self.clone().connection.send(vec![
+            "world.getBlockWithData",
+            self.server_pos_int(pos).as_str(),
+        ]);
         Block::decode(self.connection.receive())
     }
     /// update a data of a block in a position
@@ -167,14 +353,12 @@ impl Minecraft {
     ///
     /// assert_eq!(mc.clone().mc_get_block((1,2,3)),Block::from_item(STONE));
     /// ```
-    pub fn mc_set_block(self, pos: (i32, i32, i32), block: Block) {
-        self.connection.send_s(format!(
-            "world.setBlock({},{},{},{})",
-            pos.0,
-            pos.1,
-            pos.2,
-            block.to_string()
-        ));
+    pub fn mc_set_block(self, pos: impl Into<BlockPos>, block: Block) {
+        let pos = pos.into();
+        self.record(Region::block(pos), &block);
+        let pos = self.server_pos_int(pos);
+        self.connection
+            .send_s(format!("world.setBlock({},{})", pos, block));
     }
     /// update the data of the block from pos_begin to pos_end
     ///
@@ -190,14 +374,150 @@ impl Minecraft {
     ///
     /// assert_eq!(mc.clone().mc_get_block((12,13,15)),Block::from_item(DIAMOND_ORE));
     /// ```
-    pub fn mc_set_blocks(self, pos_begin: (i32, i32, i32), pos_end: (i32, i32, i32), block: Block) {
+    pub fn mc_set_blocks(
+        self,
+        pos_begin: impl Into<BlockPos>,
+        pos_end: impl Into<BlockPos>,
+        block: Block,
+    ) {
+        let (pos_begin, pos_end) = (pos_begin.into(), pos_end.into());
+        self.record(Region::new(pos_begin, pos_end), &block);
+        let (pos_begin, pos_end) = (self.server_pos_int(pos_begin), self.server_pos_int(pos_end));
         self.connection.send_s(format!(
             "world.setBlocks({},{},{})",
-            pos_to_string(pos_begin),
-            pos_to_string(pos_end),
-            block.to_string()
+            pos_begin, pos_end, block
         ));
     }
+    /// fill a region with a block
+    ///
+    /// large regions are split into several `world.setBlocks` commands
+    /// of at most `MAX_SET_BLOCKS_VOLUME` blocks, so the server does not stall
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// let floor = Region::new((-50, 63, -50), (50, 63, 50));
+    /// mc.clone().mc_set_region(floor, Block::from_item(STONE));
+    /// mc.mc_set_region(floor.translate((0, 1, 0)), Block::from_item(AIR));
+    /// ```
+    pub fn mc_set_region(self, region: impl Into<Region>, block: Block) {
+        for part in region.into().split(MAX_SET_BLOCKS_VOLUME) {
+            self.clone()
+                .mc_set_blocks(part.min(), part.max(), block.clone());
+        }
+    }
+    /// place a set of blocks in the order they can stand
+    ///
+    /// blocks are removed (replaced by air) first from the top down, then supports are placed,
+    /// then gravity blocks and attached blocks from the bottom up, and liquids at the end
+    /// (see `sort_placements`)
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// // the torch is placed after the stone it stands on
+    /// mc.mc_paste(vec![
+    ///     ((0, 65, 0).into(), Block::torch(TORCH, TorchFacing::Up)),
+    ///     ((0, 64, 0).into(), Block::from_item(STONE)),
+    /// ]);
+    /// ```
+    pub fn mc_paste(self, blocks: impl IntoIterator<Item = (BlockPos, Block)>) {
+        let mut blocks = blocks.into_iter().collect::<Vec<_>>();
+        sort_placements(&mut blocks);
+        for (pos, block) in blocks {
+            self.clone().mc_set_block(pos, block);
+        }
+    }
+    /// place a sign with text (RaspberryJuice only)
+    ///
+    /// return an error if the sign is invalid (see `Sign::validate`) or the dialect has no signs
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// let sign = Sign::wall(BlockFace::South).lines(vec!["Welcome", "to the", "castle"]);
+    /// mc.mc_set_sign((0, 65, 0), &sign).unwrap();
+    /// ```
+    pub fn mc_set_sign(self, pos: impl Into<BlockPos>, sign: &Sign) -> Result<(), Error> {
+        let pos = pos.into();
+        if self.dialect != Dialect::RaspberryJuice {
+            return Err(Error::Unsupported {
+                command: String::from("world.setSign"),
+                dialect: self.dialect,
+            });
+        }
+        sign.validate()?;
+        let block = sign.block();
+        self.record(Region::block(pos), &block);
+        let mut parts = vec![
+            String::from("world.setSign"),
+            self.server_pos_int(pos),
+            block.item().unwrap().to_string(),
+            block.data().to_string(),
+        ];
+        parts.extend(sign.escaped_lines());
+        self.connection.send(parts);
+        Ok(())
+    }
+    /// get the blocks of the cuboid between two corners (inclusive) in one command
+    ///
+    /// the server only reports the ids, the blocks are ordered by y, then x, then z
+    /// (see `cuboid_positions`)
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// let blocks = mc.mc_get_blocks((0, 64, 0), (3, 65, 3));
+    /// assert_eq!(blocks.len(), 32);
+    /// ```
+    pub fn mc_get_blocks(
+        self,
+        pos_begin: impl Into<BlockPos>,
+        pos_end: impl Into<BlockPos>,
+    ) -> Vec<Block> {
+        self.clone().connection.send(vec![
+            "world.getBlocks",
+            self.server_pos_int(pos_begin).as_str(),
+            self.server_pos_int(pos_end).as_str(),
+        ]);
+        self.connection
+            .receive()
+            .trim()
+            .split(',')
+            .filter(|i| !i.is_empty())
+            .map(|i| Block::from_item(Id(i.parse().expect("Failed to parse"))))
+            .collect()
+    }
+    /// get the blocks of a region, ordered like `Region::positions`
+    ///
+    /// large regions are read in slabs of at most `MAX_SET_BLOCKS_VOLUME` blocks
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// let region = Region::new((0, 60, 0), (15, 70, 15));
+    /// for (pos, block) in region.positions().zip(mc.mc_get_region(region)) {
+    ///     println!("{} {}", pos, block);
+    /// }
+    /// ```
+    pub fn mc_get_region(self, region: impl Into<Region>) -> Vec<Block> {
+        region
+            .into()
+            .layers(MAX_SET_BLOCKS_VOLUME)
+            .into_iter()
+            .flat_map(|layer| self.clone().mc_get_blocks(layer.min(), layer.max()))
+            .collect()
+    }
     /// get the height of the world
     /// # Example
     ///
@@ -210,17 +530,20 @@ impl Minecraft {
     ///
     /// ```
     pub fn mc_get_pos_y(self, x: i32, z: i32) -> i32 {
+        let BlockPos { x, z, .. } = self.frame.to_server_int((x, 0, z));
         self.clone().connection.send(vec![
             "world.getHeight",
             x.to_string().as_str(),
             z.to_string().as_str(),
         ]);
-        self.connection
+        let y: i32 = self
+            .connection
             .receive()
             .split_whitespace()
             .collect::<Vec<&str>>()[0]
             .parse()
-            .unwrap()
+            .unwrap();
+        y + self.frame.spawn_offset().y
     }
     /// send a message to minecraft
     ///
@@ -268,11 +591,12 @@ impl Minecraft {
     ///
     /// mc.clone().mc_set_pos((12.25f64,100.11789f64,13f64));
     ///
-    /// assert_eq!(mc.clone().mc_get_pos(),(12.25f64,100.11789f64,13f64));
+    /// assert_eq!(mc.clone().mc_get_pos(),Vec3::new(12.25f64,100.11789f64,13f64));
     /// ```
-    pub fn mc_get_pos(self) -> (f64, f64, f64) {
+    pub fn mc_get_pos(self) -> Vec3 {
         self.clone().connection.send_s("player.getPos()");
-        pos_decode(self.connection.receive())
+        self.frame
+            .from_server(pos_decode(self.connection.receive()))
     }
     /// get the integer position of the player (**cannot use it when the server has multi players**)
     /// # Example
@@ -283,13 +607,12 @@ impl Minecraft {
     ///
     /// mc.clone().mc_set_pos_int((10,75,30));
     ///
-    /// assert_eq!(mc.clone().mc_get_pos_int(),(10,75,30));
+    /// assert_eq!(mc.clone().mc_get_pos_int(),BlockPos::new(10,75,30));
     /// ```
-    pub fn mc_get_pos_int(self) -> (i32, i32, i32) {
+    pub fn mc_get_pos_int(self) -> BlockPos {
         self.clone().connection.send_s("player.getTile()");
         let receive = self.connection.receive();
-        let pos_exact = pos_decode_int(receive);
-        (pos_exact.0 as i32, pos_exact.1 as i32, pos_exact.2 as i32)
+        self.frame.from_server_int(pos_decode_int(receive))
     }
     /// set the position of the player (**cannot use it when the server has multi players**)
     ///
@@ -302,11 +625,11 @@ impl Minecraft {
     ///
     /// mc.clone().mc_set_pos((12.25f64,100.11789f64,13f64));
     ///
-    /// assert_eq!(mc.clone().mc_get_pos(),(12.25f64,100.11789f64,13f64));
+    /// assert_eq!(mc.clone().mc_get_pos(),Vec3::new(12.25f64,100.11789f64,13f64));
     /// ```
-    pub fn mc_set_pos(self, pos: (f32, f32, f32)) {
-        self.connection
-            .send(vec!["player.setPos", pos_to_string(pos).as_str()]);
+    pub fn mc_set_pos(self, pos: impl Into<Vec3>) {
+        let pos = self.server_pos(pos);
+        self.connection.send(vec!["player.setPos", pos.as_str()]);
     }
     /// set the integer position of the player (**cannot use it when the server has multi players**)
     /// # Example
@@ -317,61 +640,587 @@ impl Minecraft {
     ///
     /// mc.clone().mc_set_pos_int((10,75,30));
     ///
-    /// assert_eq!(mc.clone().mc_get_pos_int(),(10,75,30));
+    /// assert_eq!(mc.clone().mc_get_pos_int(),BlockPos::new(10,75,30));
     /// ```
-    pub fn mc_set_pos_int(self, pos: (i32, i32, i32)) {
-        self.connection
-            .send(vec!["player.setTile", pos_to_string(pos).as_str()]);
+    pub fn mc_set_pos_int(self, pos: impl Into<BlockPos>) {
+        let pos = self.server_pos_int(pos);
+        self.connection.send(vec!["player.setTile", pos.as_str()]);
     }
-    /// set the settings of the world
+    /// change a setting of the world
+    ///
+    /// return `Error::Unsupported` if the dialect does not support the setting
     /// # Example
     /// ```no_run
     /// use mcpi_rs::prelude::*;
     ///
-    /// let mc = Minecraft::connect();
+    /// let mc = Minecraft::connect().with_dialect(Dialect::PiEdition);
     ///
-    /// mc.mc_world_setting("CommandBlockOutput",true);
+    /// mc.mc_world_setting(WorldSetting::NametagsVisible, false).unwrap();
     /// ```
-    pub fn mc_world_setting(self, key: impl ToString, val: bool) {
+    pub fn mc_world_setting(self, setting: WorldSetting, val: bool) -> Result<(), Error> {
+        if !setting.is_supported_by(self.dialect) {
+            return Err(Error::Unsupported {
+                command: format!("world.setting({})", setting.key()),
+                dialect: self.dialect,
+            });
+        }
+        self.connection.send(vec![
+            "world.setting",
+            setting.key(),
+            if val { "1" } else { "0" },
+        ]);
+        Ok(())
+    }
+    /// change a setting of the player
+    ///
+    /// return `Error::Unsupported` if the dialect does not support the setting
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect().with_dialect(Dialect::PiEdition);
+    ///
+    /// mc.mc_player_setting(PlayerSetting::Autojump, false).unwrap();
+    /// ```
+    pub fn mc_player_setting(self, setting: PlayerSetting, val: bool) -> Result<(), Error> {
+        if !setting.is_supported_by(self.dialect) {
+            return Err(Error::Unsupported {
+                command: format!("player.setting({})", setting.key()),
+                dialect: self.dialect,
+            });
+        }
         self.connection.send(vec![
             "player.setting",
-            key.to_string().as_str(),
-            val.to_string().as_str(),
+            setting.key(),
+            if val { "1" } else { "0" },
         ]);
+        Ok(())
     }
-    pub fn mc_get_pos_entity_int(self, id: i32) -> (i32, i32, i32) {
+    pub fn mc_get_pos_entity_int(self, id: i32) -> BlockPos {
         self.clone()
             .connection
             .send(vec!["entity.getTile", id.to_string().as_str()]);
         let pos = pos_decode(self.connection.receive());
-        (pos.0 as i32, pos.1 as i32, pos.2 as i32)
+        self.frame.from_server_int(pos.floor())
     }
-    pub fn mc_set_pos_entity_int(self, id: i32, pos: (i32, i32, i32)) {
+    pub fn mc_set_pos_entity_int(self, id: i32, pos: impl Into<BlockPos>) {
+        let pos = self.server_pos_int(pos);
         self.connection.send(vec![
             "entity.setTile",
             id.to_string().as_str(),
-            pos_to_string(pos).as_str(),
+            pos.as_str(),
         ]);
     }
-    pub fn mc_get_pos_entity(self, id: i32) -> (f64, f64, f64) {
+    pub fn mc_get_pos_entity(self, id: i32) -> Vec3 {
         self.clone()
             .connection
             .send(vec!["entity.getPos", id.to_string().as_str()]);
-        pos_decode(self.connection.receive())
+        self.frame
+            .from_server(pos_decode(self.connection.receive()))
     }
-    pub fn mc_set_pos_entity(self, id: i32, pos: (f64, f64, f64)) {
-        self.connection.send(vec![
-            "entity.setPos",
-            id.to_string().as_str(),
-            pos_to_string(pos).as_str(),
+    pub fn mc_set_pos_entity(self, id: i32, pos: impl Into<Vec3>) {
+        let pos = self.server_pos(pos);
+        self.connection
+            .send(vec!["entity.setPos", id.to_string().as_str(), pos.as_str()]);
+    }
+    /// spawn an entity at a position and return it
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// let zombie = mc.clone().mc_spawn_entity((10.5, 64.0, 10.5), EntityType::Zombie);
+    ///
+    /// assert!(mc.mc_remove_entity(zombie.id()));
+    /// ```
+    pub fn mc_spawn_entity(self, pos: impl Into<Vec3>, kind: EntityType) -> Entity {
+        let pos = pos.into();
+        self.clone().connection.send(vec![
+            "world.spawnEntity",
+            self.server_pos(pos).as_str(),
+            kind.id().to_string().as_str(),
         ]);
+        let id = self
+            .connection
+            .receive()
+            .trim()
+            .parse()
+            .expect("Failed to parse entity id");
+        Entity::new(id, kind.id(), pos)
+    }
+    /// remove an entity, return whether the entity was found
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// let pig = mc.clone().mc_spawn_entity((0.0, 70.0, 0.0), EntityType::Pig);
+    /// mc.mc_remove_entity(pig.id());
+    /// ```
+    pub fn mc_remove_entity(self, id: i32) -> bool {
+        self.clone()
+            .connection
+            .send(vec!["world.removeEntity", id.to_string().as_str()]);
+        self.connection.receive().trim() != "0"
+    }
+    /// remove all the entities of a type (all the non-player entities if `kind` is `None`),
+    /// return how many entities were removed
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// let removed = mc.mc_remove_entities(Some(EntityType::Creeper));
+    /// ```
+    pub fn mc_remove_entities(self, kind: Option<EntityType>) -> i32 {
+        self.clone().connection.send(vec![
+            "world.removeEntities",
+            kind.map_or(-1, EntityType::id).to_string().as_str(),
+        ]);
+        self.connection
+            .receive()
+            .trim()
+            .parse()
+            .expect("Failed to parse")
+    }
+    /// get all the entities of a type (all the non-player entities if `kind` is `None`)
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// for e in mc.mc_get_entities(None) {
+    ///     println!("{} {:?} {:?}", e.id(), e.kind(), e.pos());
+    /// }
+    /// ```
+    pub fn mc_get_entities(self, kind: Option<EntityType>) -> Vec<Entity> {
+        self.clone().connection.send(vec![
+            "world.getEntities",
+            kind.map_or(-1, EntityType::id).to_string().as_str(),
+        ]);
+        let frame = self.frame;
+        Entity::decode_list(self.connection.receive())
+            .into_iter()
+            .map(|e| Entity::new(e.id(), e.type_id(), frame.from_server(e.pos())))
+            .collect()
+    }
+    /// get the entities of a type (any type if `kind` is `None`) within `radius` blocks of `center`
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// let pos = mc.clone().mc_get_pos();
+    /// let mobs = mc.mc_get_nearby_entities(pos, 16.0, Some(EntityType::Zombie));
+    /// ```
+    pub fn mc_get_nearby_entities(
+        self,
+        center: impl Into<Vec3>,
+        radius: f64,
+        kind: Option<EntityType>,
+    ) -> Vec<Entity> {
+        let center = center.into();
+        self.mc_get_entities(kind)
+            .into_iter()
+            .filter(|e| e.pos().distance_squared(center) <= radius * radius)
+            .collect()
+    }
+    /// get the entity id of a player by name (`None` if the player is not online)
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// let id = mc.mc_get_player_entity_id("Steve");
+    /// ```
+    pub fn mc_get_player_entity_id(self, name: impl ToString) -> Option<i32> {
+        self.clone()
+            .connection
+            .send(vec!["world.getPlayerId", name.to_string().as_str()]);
+        self.connection.receive().trim().parse().ok()
+    }
+    /// get the entity ids of all the players on the server
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// println!("{} players online", mc.mc_get_player_entity_ids().len());
+    /// ```
+    pub fn mc_get_player_entity_ids(self) -> Vec<i32> {
+        // entries that are not ids (such as a `Fail` reply) are skipped
+        self.query("world.getPlayerIds")
+            .trim()
+            .split('|')
+            .filter_map(|i| i.parse().ok())
+            .collect()
+    }
+    /// get (and clear) the block hits since the last poll
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// for hit in mc.mc_events_block_hits() {
+    ///     println!("{} hit {:?}", hit.entity_id, hit.pos);
+    /// }
+    /// ```
+    pub fn mc_events_block_hits(self) -> Vec<BlockEvent> {
+        self.block_hits("events.block.hits")
     }
+    /// get (and clear) the chat posts since the last poll
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// for post in mc.mc_events_chat_posts() {
+    ///     println!("{}: {}", post.entity_id, post.message);
+    /// }
+    /// ```
+    pub fn mc_events_chat_posts(self) -> Vec<ChatEvent> {
+        ChatEvent::decode_list(self.query("events.chat.posts"))
+    }
+    /// get (and clear) the projectile hits since the last poll
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// for hit in mc.mc_events_projectile_hits() {
+    ///     println!("{} shot {:?}", hit.shooter, hit.target);
+    /// }
+    /// ```
+    pub fn mc_events_projectile_hits(self) -> Vec<ProjectileEvent> {
+        self.projectile_hits("events.projectile.hits")
+    }
+    /// get (and clear) all the events since the last poll
+    ///
+    /// block hits come first, then chat posts, then projectile hits
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// for event in mc.mc_events_poll() {
+    ///     match event {
+    ///         Event::BlockHit(e) => println!("hit {:?}", e.pos),
+    ///         Event::ChatPost(e) => println!("said {}", e.message),
+    ///         Event::ProjectileHit(e) => println!("shot {:?}", e.pos),
+    ///         _ => unreachable!("only reported by watchers"),
+    ///     }
+    /// }
+    /// ```
+    pub fn mc_events_poll(self) -> Vec<Event> {
+        let mut events = Vec::new();
+        events.extend(
+            self.clone()
+                .mc_events_block_hits()
+                .into_iter()
+                .map(Event::BlockHit),
+        );
+        events.extend(
+            self.clone()
+                .mc_events_chat_posts()
+                .into_iter()
+                .map(Event::ChatPost),
+        );
+        events.extend(
+            self.mc_events_projectile_hits()
+                .into_iter()
+                .map(Event::ProjectileHit),
+        );
+        events
+    }
+    /// clear all the events which have not been polled
+    pub fn mc_events_clear(self) {
+        self.connection.send_s("events.clear()");
+    }
+    /// get (and clear) the block hits of the player since the last poll
+    pub fn mc_player_events_block_hits(self) -> Vec<BlockEvent> {
+        self.block_hits("player.events.block.hits")
+    }
+    /// get (and clear) the chat posts of the player since the last poll
+    pub fn mc_player_events_chat_posts(self) -> Vec<ChatEvent> {
+        ChatEvent::decode_list(self.query("player.events.chat.posts"))
+    }
+    /// get (and clear) the projectile hits of the player since the last poll
+    pub fn mc_player_events_projectile_hits(self) -> Vec<ProjectileEvent> {
+        self.projectile_hits("player.events.projectile.hits")
+    }
+    /// clear the events of the player which have not been polled
+    pub fn mc_player_events_clear(self) {
+        self.connection.send_s("player.events.clear()");
+    }
+    /// set the camera back to the first person view of the player
     pub fn mc_set_camera_normal(self) {
-        self.connection.send_s("camera.mode.setNormal()");
+        self.mc_set_camera_mode(CameraMode::Normal(None));
+    }
+    /// set the mode of the camera
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// let pig = mc.clone().mc_spawn_entity((0.0, 70.0, 0.0), EntityType::Pig);
+    /// mc.clone().mc_set_camera_mode(CameraMode::Follow(Some(pig.id())));
+    /// // -- snip --
+    /// mc.mc_set_camera_mode(CameraMode::Normal(None));
+    /// ```
+    pub fn mc_set_camera_mode(self, mode: CameraMode) {
+        let (command, id) = mode.command();
+        match id {
+            Some(id) => self.connection.send(vec![command, id.to_string().as_str()]),
+            None => self.connection.send(vec![command]),
+        }
+    }
+    /// set the position of the fixed camera
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// mc.clone().mc_set_camera_mode(CameraMode::Fixed);
+    /// mc.mc_set_camera_pos((0.5, 100.0, 0.5));
+    /// ```
+    pub fn mc_set_camera_pos(self, pos: impl Into<Vec3>) {
+        let pos = self.server_pos(pos);
+        self.connection.send(vec!["camera.setPos", pos.as_str()]);
     }
     pub fn auto_flush(self, auto: bool) {
         self.connection.auto_flush(auto);
     }
+    /// get the tile of an entity, `None` if the entity no longer exists
+    pub(crate) fn try_get_pos_entity_int(self, id: i32) -> Option<BlockPos> {
+        self.clone()
+            .connection
+            .send(vec!["entity.getTile", id.to_string().as_str()]);
+        let reply = self.connection.receive();
+        let vec = reply.trim().split(',').collect::<Vec<&str>>();
+        if vec.len() != 3 {
+            return None;
+        }
+        let pos = Vec3::new(
+            vec[0].parse().ok()?,
+            vec[1].parse().ok()?,
+            vec[2].parse().ok()?,
+        );
+        Some(self.frame.from_server_int(pos.floor()))
+    }
+    /// save the state of the world on the server (Minecraft: Pi Edition only)
+    pub fn mc_checkpoint_save(self) -> Result<(), Error> {
+        self.pi_only("world.checkpoint.save")?;
+        self.connection.send(vec!["world.checkpoint.save"]);
+        Ok(())
+    }
+    /// restore the state saved by `mc_checkpoint_save` (Minecraft: Pi Edition only)
+    pub fn mc_checkpoint_restore(self) -> Result<(), Error> {
+        self.pi_only("world.checkpoint.restore")?;
+        self.connection.send(vec!["world.checkpoint.restore"]);
+        Ok(())
+    }
+    /// run `f` and restore the world if it returns an error or panics
+    ///
+    /// Minecraft: Pi Edition saves the whole world with `world.checkpoint.save`.
+    /// other dialects emulate the checkpoint: the connection passed to `f` remembers the blocks
+    /// before `mc_set_block`, `mc_set_blocks` and `mc_set_sign` overwrite them,
+    /// and only those blocks are restored (this costs extra reads for every write)
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mc = Minecraft::connect();
+    ///
+    /// let result: Result<(), String> = mc.checkpoint(|mc| {
+    ///     mc.clone().mc_set_blocks((0, 64, 0), (10, 74, 10), Block::from_item(GLASS));
+    ///     Err(String::from("changed my mind"))
+    /// });
+    /// // the glass is gone again
+    /// ```
+    pub fn checkpoint<T, E>(self, f: impl FnOnce(Minecraft) -> Result<T, E>) -> Result<T, E> {
+        let mut guard = CheckpointGuard {
+            mc: self.clone(),
+            armed: true,
+        };
+        let result = if self.dialect == Dialect::PiEdition {
+            self.clone()
+                .mc_checkpoint_save()
+                .expect("Pi Edition has checkpoints");
+            f(self)
+        } else {
+            let journal = Journal::default();
+            guard.mc.journal = Some(journal.clone());
+            f(Minecraft {
+                journal: Some(journal),
+                ..self
+            })
+        };
+        guard.armed = result.is_err();
+        result
+    }
+    /// restore the blocks remembered by an emulated checkpoint
+    fn restore_journal(self) {
+        let journal = match self.journal {
+            Some(ref journal) => journal.lock().map(|j| j.clone()).unwrap_or_else(|e| {
+                // a panic while recording leaves the journal usable
+                e.into_inner().clone()
+            }),
+            None => return,
+        };
+        let mc = Minecraft {
+            journal: None,
+            ..self
+        };
+        // the attached blocks written by the checkpoint are removed first, from the top down,
+        // so they do not pop off as items when the blocks holding them are restored
+        let mut attached = journal
+            .iter()
+            .filter(|(_, (_, written))| written.properties().needs_support)
+            .map(|(&pos, _)| pos)
+            .collect::<Vec<_>>();
+        attached.sort_by_key(|pos| Reverse(pos.y));
+        for pos in attached {
+            mc.clone().mc_set_block(pos, Block::from_item(AIR));
+        }
+        mc.mc_paste(journal.into_iter().map(|(pos, (saved, _))| (pos, saved)));
+    }
+    /// remember the blocks of a cuboid before they are overwritten with `block` inside an
+    /// emulated checkpoint
+    fn record(&self, region: Region, block: &Block) {
+        let journal = match &self.journal {
+            Some(journal) => journal,
+            None => return,
+        };
+        let positions = region.positions().collect::<Vec<_>>();
+        let mut saved = journal.lock().unwrap_or_else(|e| e.into_inner());
+        if !positions.iter().all(|p| saved.contains_key(p)) {
+            let mc = Minecraft {
+                journal: None,
+                ..self.clone()
+            };
+            let blocks = mc.clone().mc_get_blocks(region.min(), region.max());
+            let unsaved = positions
+                .iter()
+                .copied()
+                .zip(blocks)
+                .filter(|(pos, _)| !saved.contains_key(pos))
+                .collect::<Vec<_>>();
+            // only the ids are known, the data values of the other blocks are read in one batch
+            let solid = unsaved
+                .iter()
+                .filter(|(_, old)| old.item() != AIR)
+                .map(|&(pos, _)| pos)
+                .collect::<Vec<_>>();
+            let mut with_data = mc.get_blocks_with_data(&solid).into_iter();
+            for (pos, old) in unsaved {
+                let old = if old.item() == AIR {
+                    old
+                } else {
+                    with_data.next().expect("a reply for every block")
+                };
+                saved.insert(pos, (old, block.clone()));
+            }
+        }
+        for pos in positions {
+            if let Some((_, written)) = saved.get_mut(&pos) {
+                *written = block.clone();
+            }
+        }
+    }
+    fn pi_only(&self, command: &str) -> Result<(), Error> {
+        if self.dialect == Dialect::PiEdition {
+            Ok(())
+        } else {
+            Err(Error::Unsupported {
+                command: command.to_string(),
+                dialect: self.dialect,
+            })
+        }
+    }
+    /// poll block hits and convert their positions to world coordinates
+    fn block_hits(self, command: &str) -> Vec<BlockEvent> {
+        let frame = self.frame;
+        BlockEvent::decode_list(self.query(command))
+            .into_iter()
+            .map(|mut e| {
+                e.pos = frame.from_server_int(e.pos);
+                e
+            })
+            .collect()
+    }
+    /// poll projectile hits and convert their positions to world coordinates
+    fn projectile_hits(self, command: &str) -> Vec<ProjectileEvent> {
+        let frame = self.frame;
+        ProjectileEvent::decode_list(self.query(command))
+            .into_iter()
+            .map(|mut e| {
+                e.pos = frame.from_server_int(e.pos);
+                e
+            })
+            .collect()
+    }
+    /// convert world coordinates to the argument string of a server position
+    fn server_pos_int(&self, pos: impl Into<BlockPos>) -> String {
+        self.frame.to_server_int(pos).to_string()
+    }
+    /// convert world coordinates to the argument string of a server position
+    fn server_pos(&self, pos: impl Into<Vec3>) -> String {
+        self.frame.to_server(pos).to_string()
+    }
+    /// get the blocks with data of many positions, sending every `world.getBlockWithData`
+    /// before reading the replies so the reads cost a single round-trip
+    fn get_blocks_with_data(&self, positions: &[BlockPos]) -> Vec<Block> {
+        for &pos in positions {
+            self.connection.clone().send(vec![
+                "world.getBlockWithData",
+                self.server_pos_int(pos).as_str(),
+            ]);
+        }
+        self.connection
+            .clone()
+            .receive_lines(positions.len())
+            .into_iter()
+            .map(Block::decode)
+            .collect()
+    }
+    /// send a command without arguments and receive the reply
+    fn query(self, command: &str) -> String {
+        self.clone().connection.send(vec![command]);
+        self.connection.receive()
+    }
+}
+/// restore the checkpoint when dropped while armed (on error or panic)
+struct CheckpointGuard {
+    mc: Minecraft,
+    armed: bool,
+}
+impl Drop for CheckpointGuard {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+        if self.mc.dialect == Dialect::PiEdition {
+            self.mc
+                .clone()
+                .mc_checkpoint_restore()
+                .expect("Pi Edition has checkpoints");
+        } else {
+            self.mc.clone().restore_journal();
+        }
+    }
 }
 #[derive(Clone)]
 pub struct McDrawing {
@@ -396,79 +1245,375 @@ impl McDrawing {
     /// mcd.drawline(Block::from_item(OBSIDIAN),(-1,1,1),(5,3,-1));
     /// assert_eq!(mc.mc_get_block((0,1,1)),Block::from_item(OBSIDIAN));
     /// ```
-    pub fn drawline(self, block: Block, mut pos_start: (i32, i32, i32), pos_end: (i32, i32, i32)) {
-        let mut points: Vec<(i32, i32, i32)> = vec![pos_start];
-        let (dx, dy, dz) = (
-            (pos_start.0 - pos_end.0).abs(),
-            (pos_start.1 - pos_end.1).abs(),
-            (pos_start.2 - pos_end.2).abs(),
-        );
-        let xs = if pos_end.0 > pos_start.0 { 1 } else { -1 };
-        let ys = if pos_end.1 > pos_start.1 { 1 } else { -1 };
-        let zs = if pos_end.2 > pos_start.2 { 1 } else { -1 };
-        let (mut p1, mut p2) = (2 * dy - dx, 2 * dz - dx);
-        //Driving axis is X-axis
-        if dx >= dy && dx >= dz {
-            while pos_start.0 != pos_end.0 {
-                pos_start.0 += xs;
-                if p1 >= 0 {
-                    pos_start.1 += ys;
-                    p1 -= 2 * dx;
-                }
-                if p2 >= 0 {
-                    pos_start.2 += zs;
-                    p2 -= 2 * dx;
-                }
-                p1 += 2 * dy;
-                p2 += 2 * dz;
-                points.push(pos_start);
-            }
-        }
-        //Driving axis is Y-axis
-        else if dy >= dx && dy >= dz {
-            p1 = 2 * dx - dy;
-            p2 = 2 * dz - dy;
-            while pos_start.1 != pos_end.1 {
-                pos_start.1 += ys;
-                if p1 >= 0 {
-                    pos_start.0 += xs;
-                    p1 -= 2 * dy;
-                }
-                if p2 >= 0 {
-                    pos_start.2 += zs;
-                    p2 -= 2 * dy;
-                }
-                p1 += 2 * dx;
-                p2 += 2 * dz;
-                points.push(pos_start);
-            }
+    pub fn drawline(
+        self,
+        block: Block,
+        pos_start: impl Into<BlockPos>,
+        pos_end: impl Into<BlockPos>,
+    ) {
+        self.drawpoints(shapes::line(pos_start, pos_end), block);
+    }
+    /// draw a horizontal circle in minecraft (use midpoint circle algorithm)
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    /// let mcd = McDrawing::new(Minecraft::connect());
+    /// mcd.drawcircle((0, 80, 0), 10, Block::from_item(GOLD_BLOCK));
+    /// ```
+    pub fn drawcircle(self, center_pos: impl Into<BlockPos>, radius: u32, block: Block) {
+        self.drawcircle_on(center_pos, radius, Axis::Y, block);
+    }
+    /// draw a circle in the plane perpendicular to `normal`
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    /// let mcd = McDrawing::new(Minecraft::connect());
+    /// // a standing ring facing north
+    /// mcd.drawcircle_on((0, 80, 0), 10, Axis::Z, Block::from_item(GOLD_BLOCK));
+    /// ```
+    pub fn drawcircle_on(
+        self,
+        center_pos: impl Into<BlockPos>,
+        radius: u32,
+        normal: Axis,
+        block: Block,
+    ) {
+        self.drawpoints(shapes::circle(center_pos, radius, normal), block);
+    }
+    /// draw a filled circle in the plane perpendicular to `normal`
+    pub fn drawdisc(
+        self,
+        center_pos: impl Into<BlockPos>,
+        radius: u32,
+        normal: Axis,
+        block: Block,
+    ) {
+        self.drawpoints(shapes::disc(center_pos, radius, normal), block);
+    }
+    /// draw an ellipse, filled or not, in the plane perpendicular to `normal`
+    /// (see `shapes::ellipse` for the radii)
+    pub fn drawellipse(
+        self,
+        center_pos: impl Into<BlockPos>,
+        radii: (u32, u32),
+        normal: Axis,
+        filled: bool,
+        block: Block,
+    ) {
+        let points = if filled {
+            shapes::filled_ellipse(center_pos, radii.0, radii.1, normal)
         } else {
-            //Driving axis is Z-axis
-            p1 = 2 * dy - dz;
-            p2 = 2 * dx - dz;
-            while pos_start.2 != pos_end.2 {
-                pos_start.2 += zs;
-                if p1 >= 0 {
-                    pos_start.1 += ys;
-                    p1 -= 2 * dz;
-                }
-                if p2 >= 0 {
-                    pos_start.0 += xs;
-                    p2 -= 2 * dz;
-                }
-                p1 += 2 * dy;
-                p2 += 2 * dx;
-                points.push(pos_start);
+            shapes::ellipse(center_pos, radii.0, radii.1, normal)
+        };
+        self.drawpoints(points, block);
+    }
+    /// draw a flat ring `thickness` blocks wide in the plane perpendicular to `normal`
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    /// let mcd = McDrawing::new(Minecraft::connect());
+    /// mcd.drawring((0, 64, 0), 20, 3, Axis::Y, Block::from_item(COBBLESTONE));
+    /// ```
+    pub fn drawring(
+        self,
+        center_pos: impl Into<BlockPos>,
+        radius: u32,
+        thickness: u32,
+        normal: Axis,
+        block: Block,
+    ) {
+        self.drawpoints(shapes::ring(center_pos, radius, thickness, normal), block);
+    }
+    /// draw a filled sphere
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    /// let mcd = McDrawing::new(Minecraft::connect());
+    /// mcd.drawsphere((0, 100, 0), 12, Block::from_item(STONE));
+    /// ```
+    pub fn drawsphere(self, center_pos: impl Into<BlockPos>, radius: u32, block: Block) {
+        self.drawpoints(shapes::sphere(center_pos, radius), block);
+    }
+    /// draw a watertight hollow sphere with a shell `thickness` blocks thick
+    pub fn drawhollowsphere(
+        self,
+        center_pos: impl Into<BlockPos>,
+        radius: u32,
+        thickness: u32,
+        block: Block,
+    ) {
+        self.drawpoints(shapes::hollow_sphere(center_pos, radius, thickness), block);
+    }
+    /// draw an ellipsoid with the radii along x, y and z, filled if `thickness` is `None`
+    pub fn drawellipsoid(
+        self,
+        center_pos: impl Into<BlockPos>,
+        radii: (u32, u32, u32),
+        thickness: Option<u32>,
+        block: Block,
+    ) {
+        let points = match thickness {
+            Some(thickness) => shapes::hollow_ellipsoid(center_pos, radii, thickness),
+            None => shapes::ellipsoid(center_pos, radii),
+        };
+        self.drawpoints(points, block);
+    }
+    /// draw a half of an ellipsoid cut across `axis`, such as a dome (`Axis::Y`, `Half::Upper`)
+    /// or a bowl (`Axis::Y`, `Half::Lower`), filled if `thickness` is `None`
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    /// let mcd = McDrawing::new(Minecraft::connect());
+    /// // a glass dome over a garden
+    /// mcd.drawdome((0, 64, 0), (15, 10, 15), Some(1), Axis::Y, Half::Upper, Block::from_item(GLASS));
+    /// ```
+    pub fn drawdome(
+        self,
+        center_pos: impl Into<BlockPos>,
+        radii: (u32, u32, u32),
+        thickness: Option<u32>,
+        axis: Axis,
+        half: Half,
+        block: Block,
+    ) {
+        let center_pos = center_pos.into();
+        let points = match thickness {
+            Some(thickness) => shapes::hollow_ellipsoid(center_pos, radii, thickness),
+            None => shapes::ellipsoid(center_pos, radii),
+        };
+        self.drawpoints(shapes::half(points, center_pos, axis, half), block);
+    }
+    /// draw a cylinder around the axis from `pos_start` to `pos_end`, in any direction
+    ///
+    /// filled if `thickness` is `None`, else a pipe open at both ends
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    /// let mcd = McDrawing::new(Minecraft::connect());
+    /// // a tower and a pipe leaving it
+    /// mcd.clone().drawcylinder((0, 64, 0), (0, 90, 0), 6, Some(1), Block::from_item(STONE_BRICK));
+    /// mcd.drawcylinder((6, 70, 0), (40, 75, 10), 2, Some(1), Block::from_item(IRON_BLOCK));
+    /// ```
+    pub fn drawcylinder(
+        self,
+        pos_start: impl Into<BlockPos>,
+        pos_end: impl Into<BlockPos>,
+        radius: u32,
+        thickness: Option<u32>,
+        block: Block,
+    ) {
+        self.drawfrustum(pos_start, pos_end, (radius, radius), thickness, block);
+    }
+    /// draw a cone with its base around `pos_start` and its tip at `pos_end`
+    ///
+    /// filled if `thickness` is `None`, else open at its base
+    pub fn drawcone(
+        self,
+        pos_start: impl Into<BlockPos>,
+        pos_end: impl Into<BlockPos>,
+        radius: u32,
+        thickness: Option<u32>,
+        block: Block,
+    ) {
+        self.drawfrustum(pos_start, pos_end, (radius, 0), thickness, block);
+    }
+    /// draw a truncated cone with the radii at `pos_start` and `pos_end`
+    ///
+    /// filled if `thickness` is `None`, else open at both ends
+    pub fn drawfrustum(
+        self,
+        pos_start: impl Into<BlockPos>,
+        pos_end: impl Into<BlockPos>,
+        radii: (u32, u32),
+        thickness: Option<u32>,
+        block: Block,
+    ) {
+        let points = match thickness {
+            Some(thickness) => {
+                shapes::hollow_frustum(pos_start, pos_end, radii.0, radii.1, thickness)
             }
-        }
-        for i in points {
-            self.clone().mc.mc_set_block(i, block.clone());
-        }
+            None => shapes::frustum(pos_start, pos_end, radii.0, radii.1),
+        };
+        self.drawpoints(points, block);
     }
-    /// draw circle in minecraft (use midpoint circle algorithm)
+    /// draw a torus around `center_pos` with the hole along `normal`
     ///
-    /// **TODO**
-    pub fn drawcircle(self, center_pos: (i32, i32, i32), radius: u32, block: Block) {
-        todo!("drawcircle");
+    /// `radii` are the distance from the center to the middle of the tube and the radius of
+    /// the tube
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    /// let mcd = McDrawing::new(Minecraft::connect());
+    /// // a ring floating in the sky, standing up
+    /// mcd.drawtorus((0, 120, 0), (20, 3), Axis::X, Block::from_item(GOLD_BLOCK));
+    /// ```
+    pub fn drawtorus(
+        self,
+        center_pos: impl Into<BlockPos>,
+        radii: (u32, u32),
+        normal: Axis,
+        block: Block,
+    ) {
+        self.drawpoints(shapes::torus(center_pos, radii.0, radii.1, normal), block);
+    }
+    /// draw a square pyramid standing on `base_pos`, filled if `thickness` is `None`
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    /// let mcd = McDrawing::new(Minecraft::connect());
+    /// mcd.drawpyramid((0, 64, 0), 30, 31, Some(1), Block::from_item(SANDSTONE));
+    /// ```
+    pub fn drawpyramid(
+        self,
+        base_pos: impl Into<BlockPos>,
+        half_width: u32,
+        height: u32,
+        thickness: Option<u32>,
+        block: Block,
+    ) {
+        let points = match thickness {
+            Some(thickness) => shapes::hollow_pyramid(base_pos, half_width, height, thickness),
+            None => shapes::pyramid(base_pos, half_width, height),
+        };
+        self.drawpoints(points, block);
+    }
+    /// draw a stepped pyramid standing on `base_pos`, with steps `step_height` blocks high and
+    /// `step_width` blocks narrower on each side than the step below
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    /// let mcd = McDrawing::new(Minecraft::connect());
+    /// mcd.drawsteppedpyramid((0, 64, 0), 20, 4, 3, Block::from_item(STONE_BRICK));
+    /// ```
+    pub fn drawsteppedpyramid(
+        self,
+        base_pos: impl Into<BlockPos>,
+        half_width: u32,
+        step_width: u32,
+        step_height: u32,
+        block: Block,
+    ) {
+        let points = shapes::stepped_pyramid(base_pos, half_width, step_width, step_height);
+        self.drawpoints(points, block);
+    }
+    /// draw a prism of a regular polygon with `sides` corners (3 for a triangular prism, 6 for
+    /// a hexagonal one), `length` blocks long from `base_pos` along `axis`
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    /// let mcd = McDrawing::new(Minecraft::connect());
+    /// // a roof along x and a hexagonal column
+    /// mcd.clone().drawprism((0, 70, 0), 5, 3, 16, Axis::X, Block::from_item(BRICK_BLOCK));
+    /// mcd.drawprism((0, 64, 10), 3, 6, 12, Axis::Y, Block::from_item(QUARTZ_BLOCK));
+    /// ```
+    pub fn drawprism(
+        self,
+        base_pos: impl Into<BlockPos>,
+        radius: u32,
+        sides: u32,
+        length: u32,
+        axis: Axis,
+        block: Block,
+    ) {
+        self.drawpoints(shapes::prism(base_pos, radius, sides, length, axis), block);
+    }
+    /// draw an arch springing from the height of `base_pos`, in the vertical plane
+    /// perpendicular to `normal`, around an opening `2 * radius + 1` blocks wide
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    /// let mcd = McDrawing::new(Minecraft::connect());
+    /// mcd.drawarch((0, 70, 0), 4, 2, Axis::Z, ArchStyle::Pointed, Block::from_item(STONE_BRICK));
+    /// ```
+    pub fn drawarch(
+        self,
+        base_pos: impl Into<BlockPos>,
+        radius: u32,
+        thickness: u32,
+        normal: Axis,
+        style: ArchStyle,
+        block: Block,
+    ) {
+        self.drawpoints(
+            shapes::arch(base_pos, radius, thickness, normal, style),
+            block,
+        );
+    }
+    /// draw a triangle between three points, filled or only its edges (a wireframe)
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    /// let mcd = McDrawing::new(Minecraft::connect());
+    /// mcd.drawtriangle((0, 64, 0), (20, 64, 5), (10, 80, 10), true, Block::from_item(GLASS));
+    /// ```
+    pub fn drawtriangle(
+        self,
+        pos_a: impl Into<BlockPos>,
+        pos_b: impl Into<BlockPos>,
+        pos_c: impl Into<BlockPos>,
+        filled: bool,
+        block: Block,
+    ) {
+        self.drawface(&[pos_a.into(), pos_b.into(), pos_c.into()], filled, block);
+    }
+    /// draw a face with the corners `vertices`, filled or only its edges, like `drawFace` of
+    /// the python `minecraftstuff`
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    /// let mcd = McDrawing::new(Minecraft::connect());
+    /// // a sloped roof
+    /// let roof = [
+    ///     BlockPos::new(0, 70, 0),
+    ///     BlockPos::new(10, 70, 0),
+    ///     BlockPos::new(10, 75, 5),
+    ///     BlockPos::new(0, 75, 5),
+    /// ];
+    /// mcd.drawface(&roof, true, Block::from_item(WOOD_PLANKS));
+    /// ```
+    pub fn drawface(self, vertices: &[BlockPos], filled: bool, block: Block) {
+        let points = if filled {
+            shapes::polygon(vertices, &[])
+        } else {
+            shapes::polygon_outline(vertices)
+        };
+        self.drawpoints(points, block);
+    }
+    /// draw a filled planar polygon with the polygons `holes` cut out of it
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    /// let mcd = McDrawing::new(Minecraft::connect());
+    /// // a floor around a well
+    /// let floor = [
+    ///     BlockPos::new(0, 64, 0),
+    ///     BlockPos::new(12, 64, 0),
+    ///     BlockPos::new(12, 64, 12),
+    ///     BlockPos::new(0, 64, 12),
+    /// ];
+    /// let well = vec![
+    ///     BlockPos::new(5, 64, 5),
+    ///     BlockPos::new(7, 64, 5),
+    ///     BlockPos::new(7, 64, 7),
+    ///     BlockPos::new(5, 64, 7),
+    /// ];
+    /// mcd.drawpolygon(&floor, &[well], Block::from_item(COBBLESTONE));
+    /// ```
+    pub fn drawpolygon(self, vertices: &[BlockPos], holes: &[Vec<BlockPos>], block: Block) {
+        self.drawpoints(shapes::polygon(vertices, holes), block);
+    }
+    /// draw a set of points (such as a shape of the `shapes` module) with a block
+    ///
+    /// the blocks are placed in the order of `Minecraft::mc_paste`
+    /// # Example
+    /// ```no_run
+    /// use mcpi_rs::prelude::*;
+    /// let mcd = McDrawing::new(Minecraft::connect());
+    /// let points = shapes::disc((0, 64, 0), 8, Axis::Y).into_iter().filter(|p| p.x >= 0);
+    /// mcd.drawpoints(points, Block::from_item(SANDSTONE));
+    /// ```
+    pub fn drawpoints(self, points: impl IntoIterator<Item = BlockPos>, block: Block) {
+        self.mc
+            .mc_paste(points.into_iter().map(|p| (p, block.clone())));
     }
 }
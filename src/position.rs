@@ -0,0 +1,375 @@
+//! The position module of mcpi-rs
+//! `BlockPos` (the position of a block) and `Vec3` (an exact position, such as an entity's)
+//!
+//! every method taking a position accepts a tuple as well, and with the cargo features
+//! `glam`, `nalgebra` and `mint` the vector types of those crates
+//! (`glam::IVec3`/`DVec3`/`Vec3`, `nalgebra::Point3`/`Vector3`, `mint::Point3`/`Vector3`)
+//! # Example
+//!
+//! ```
+//! use mcpi_rs::prelude::*;
+//!
+//! let player = Vec3::new(10.7, 64.0, -3.2);
+//! let feet = player.floor();
+//! assert_eq!(feet, BlockPos::new(10, 64, -4));
+//! assert_eq!(feet + BlockPos::new(0, 1, 0), BlockPos::from((10, 65, -4)));
+//!
+//! # #[cfg(feature = "glam")]
+//! # {
+//! let v: glam::IVec3 = feet.into();
+//! assert_eq!(BlockPos::from(v * 2), BlockPos::new(20, 128, -8));
+//! # }
+//! ```
+use crate::events::BlockFace;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// an axis of the world
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+impl Axis {
+    /// every axis
+    pub const ALL: [Axis; 3] = [Axis::X, Axis::Y, Axis::Z];
+}
+/// the integer position of a block
+#[derive(Clone, Copy, Debug, Default, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlockPos {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+impl BlockPos {
+    /// (0, 0, 0)
+    pub const ORIGIN: BlockPos = BlockPos { x: 0, y: 0, z: 0 };
+    /// construct a position
+    pub const fn new(x: i32, y: i32, z: i32) -> BlockPos {
+        BlockPos { x, y, z }
+    }
+    /// get the coordinate on an axis
+    pub fn get(self, axis: Axis) -> i32 {
+        match axis {
+            Axis::X => self.x,
+            Axis::Y => self.y,
+            Axis::Z => self.z,
+        }
+    }
+    /// get the position with the coordinate on an axis replaced
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(BlockPos::new(1, 2, 3).with(Axis::Y, 64), BlockPos::new(1, 64, 3));
+    /// ```
+    pub fn with(mut self, axis: Axis, value: i32) -> BlockPos {
+        match axis {
+            Axis::X => self.x = value,
+            Axis::Y => self.y = value,
+            Axis::Z => self.z = value,
+        }
+        self
+    }
+    /// get the position moved by `dx`, `dy` and `dz`
+    pub fn offset(self, dx: i32, dy: i32, dz: i32) -> BlockPos {
+        BlockPos::new(self.x + dx, self.y + dy, self.z + dz)
+    }
+    /// get the position next to this one on a face
+    pub fn neighbor(self, face: BlockFace) -> BlockPos {
+        face.adjacent(self)
+    }
+    /// get the six positions sharing a face with this one (in the order of `BlockFace::ALL`)
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let n = BlockPos::new(0, 64, 0).neighbors();
+    /// assert_eq!(n[0], BlockPos::new(0, 63, 0));
+    /// assert_eq!(n[1], BlockPos::new(0, 65, 0));
+    /// ```
+    pub fn neighbors(self) -> [BlockPos; 6] {
+        BlockFace::ALL.map(|face| self.neighbor(face))
+    }
+    /// get the squared distance to another position
+    pub fn distance_squared(self, other: BlockPos) -> i64 {
+        let d = other - self;
+        d.x as i64 * d.x as i64 + d.y as i64 * d.y as i64 + d.z as i64 * d.z as i64
+    }
+    /// get the distance to another position
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(BlockPos::new(0, 0, 0).distance(BlockPos::new(3, 4, 0)), 5.0);
+    /// ```
+    pub fn distance(self, other: BlockPos) -> f64 {
+        (self.distance_squared(other) as f64).sqrt()
+    }
+    /// get the number of blocks to walk to another position along the axes
+    pub fn manhattan_distance(self, other: BlockPos) -> i32 {
+        let d = other - self;
+        d.x.abs() + d.y.abs() + d.z.abs()
+    }
+    /// get the position as a `Vec3` (the lower corner of the block)
+    pub fn to_vec3(self) -> Vec3 {
+        Vec3::new(self.x as f64, self.y as f64, self.z as f64)
+    }
+    /// get the center of the bottom of the block, where an entity stands
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(BlockPos::new(1, 64, -2).bottom_center(), Vec3::new(1.5, 64.0, -1.5));
+    /// ```
+    pub fn bottom_center(self) -> Vec3 {
+        Vec3::new(self.x as f64 + 0.5, self.y as f64, self.z as f64 + 0.5)
+    }
+    /// get the smallest coordinates of two positions
+    pub fn min(self, other: BlockPos) -> BlockPos {
+        BlockPos::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+    /// get the largest coordinates of two positions
+    pub fn max(self, other: BlockPos) -> BlockPos {
+        BlockPos::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+}
+/// the position as sent to the server (`x,y,z`)
+impl Display for BlockPos {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+impl From<(i32, i32, i32)> for BlockPos {
+    fn from(pos: (i32, i32, i32)) -> BlockPos {
+        BlockPos::new(pos.0, pos.1, pos.2)
+    }
+}
+impl From<[i32; 3]> for BlockPos {
+    fn from(pos: [i32; 3]) -> BlockPos {
+        BlockPos::new(pos[0], pos[1], pos[2])
+    }
+}
+impl From<BlockPos> for (i32, i32, i32) {
+    fn from(pos: BlockPos) -> (i32, i32, i32) {
+        (pos.x, pos.y, pos.z)
+    }
+}
+impl From<BlockPos> for [i32; 3] {
+    fn from(pos: BlockPos) -> [i32; 3] {
+        [pos.x, pos.y, pos.z]
+    }
+}
+impl Add for BlockPos {
+    type Output = BlockPos;
+    fn add(self, other: BlockPos) -> BlockPos {
+        BlockPos::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+impl Sub for BlockPos {
+    type Output = BlockPos;
+    fn sub(self, other: BlockPos) -> BlockPos {
+        BlockPos::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+impl Neg for BlockPos {
+    type Output = BlockPos;
+    fn neg(self) -> BlockPos {
+        BlockPos::new(-self.x, -self.y, -self.z)
+    }
+}
+impl Mul<i32> for BlockPos {
+    type Output = BlockPos;
+    fn mul(self, k: i32) -> BlockPos {
+        BlockPos::new(self.x * k, self.y * k, self.z * k)
+    }
+}
+impl AddAssign for BlockPos {
+    fn add_assign(&mut self, other: BlockPos) {
+        *self = *self + other;
+    }
+}
+impl SubAssign for BlockPos {
+    fn sub_assign(&mut self, other: BlockPos) {
+        *self = *self - other;
+    }
+}
+
+/// an exact position (or a direction)
+#[derive(Clone, Copy, Debug, Default, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+impl Vec3 {
+    /// (0, 0, 0)
+    pub const ZERO: Vec3 = Vec3 {
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+    /// construct a vector
+    pub const fn new(x: f64, y: f64, z: f64) -> Vec3 {
+        Vec3 { x, y, z }
+    }
+    /// get the block containing the position
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(Vec3::new(-0.5, 64.9, 2.0).floor(), BlockPos::new(-1, 64, 2));
+    /// ```
+    pub fn floor(self) -> BlockPos {
+        BlockPos::new(
+            self.x.floor() as i32,
+            self.y.floor() as i32,
+            self.z.floor() as i32,
+        )
+    }
+    /// get the nearest block corner
+    pub fn round(self) -> BlockPos {
+        BlockPos::new(
+            self.x.round() as i32,
+            self.y.round() as i32,
+            self.z.round() as i32,
+        )
+    }
+    /// get the dot product
+    pub fn dot(self, other: Vec3) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+    /// get the cross product
+    pub fn cross(self, other: Vec3) -> Vec3 {
+        Vec3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+    /// get the length of the vector
+    pub fn length(self) -> f64 {
+        self.dot(self).sqrt()
+    }
+    /// get the vector with the same direction and a length of 1 (or zero if it is zero)
+    pub fn normalize(self) -> Vec3 {
+        let length = self.length();
+        if length == 0.0 {
+            Vec3::ZERO
+        } else {
+            self / length
+        }
+    }
+    /// get the squared distance to another position
+    pub fn distance_squared(self, other: Vec3) -> f64 {
+        let d = other - self;
+        d.dot(d)
+    }
+    /// get the distance to another position
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(Vec3::new(0.5, 0.0, 0.5).distance(Vec3::new(3.5, 4.0, 0.5)), 5.0);
+    /// ```
+    pub fn distance(self, other: Vec3) -> f64 {
+        self.distance_squared(other).sqrt()
+    }
+    /// get the position at `t` (0..=1) of the way to another position
+    pub fn lerp(self, other: Vec3, t: f64) -> Vec3 {
+        self + (other - self) * t
+    }
+}
+/// the position as sent to the server (`x,y,z`)
+impl Display for Vec3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+impl From<(f64, f64, f64)> for Vec3 {
+    fn from(pos: (f64, f64, f64)) -> Vec3 {
+        Vec3::new(pos.0, pos.1, pos.2)
+    }
+}
+impl From<(f32, f32, f32)> for Vec3 {
+    fn from(pos: (f32, f32, f32)) -> Vec3 {
+        Vec3::new(pos.0 as f64, pos.1 as f64, pos.2 as f64)
+    }
+}
+impl From<(i32, i32, i32)> for Vec3 {
+    fn from(pos: (i32, i32, i32)) -> Vec3 {
+        BlockPos::from(pos).to_vec3()
+    }
+}
+impl From<[f64; 3]> for Vec3 {
+    fn from(pos: [f64; 3]) -> Vec3 {
+        Vec3::new(pos[0], pos[1], pos[2])
+    }
+}
+impl From<BlockPos> for Vec3 {
+    fn from(pos: BlockPos) -> Vec3 {
+        pos.to_vec3()
+    }
+}
+impl From<Vec3> for (f64, f64, f64) {
+    fn from(pos: Vec3) -> (f64, f64, f64) {
+        (pos.x, pos.y, pos.z)
+    }
+}
+impl From<Vec3> for [f64; 3] {
+    fn from(pos: Vec3) -> [f64; 3] {
+        [pos.x, pos.y, pos.z]
+    }
+}
+impl Add for Vec3 {
+    type Output = Vec3;
+    fn add(self, other: Vec3) -> Vec3 {
+        Vec3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+impl Sub for Vec3 {
+    type Output = Vec3;
+    fn sub(self, other: Vec3) -> Vec3 {
+        Vec3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+impl Neg for Vec3 {
+    type Output = Vec3;
+    fn neg(self) -> Vec3 {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+}
+impl Mul<f64> for Vec3 {
+    type Output = Vec3;
+    fn mul(self, k: f64) -> Vec3 {
+        Vec3::new(self.x * k, self.y * k, self.z * k)
+    }
+}
+impl Div<f64> for Vec3 {
+    type Output = Vec3;
+    fn div(self, k: f64) -> Vec3 {
+        Vec3::new(self.x / k, self.y / k, self.z / k)
+    }
+}
+impl AddAssign for Vec3 {
+    fn add_assign(&mut self, other: Vec3) {
+        *self = *self + other;
+    }
+}
+impl SubAssign for Vec3 {
+    fn sub_assign(&mut self, other: Vec3) {
+        *self = *self - other;
+    }
+}
@@ -1,5 +1,19 @@
 //! the prelude module of mcpi-rs
+pub use crate::camera::*;
+pub use crate::commands::*;
 pub use crate::connection::*;
+pub use crate::entities::*;
+pub use crate::error::*;
+pub use crate::event_loop::*;
 pub use crate::events::*;
 pub use crate::items::*;
 pub use crate::minecraft::*;
+pub use crate::position::*;
+pub use crate::properties::*;
+pub use crate::region::*;
+pub use crate::settings::*;
+pub use crate::shapes::{self, ArchStyle, Half};
+pub use crate::sign::*;
+pub use crate::states::*;
+pub use crate::variants::*;
+pub use crate::watchers::*;
@@ -0,0 +1,190 @@
+//! The properties module of mcpi-rs
+//! what the generators need to know about a block: whether it is solid, transparent, a liquid,
+//! pulled down by gravity, emits light or needs a block to hold it
+//!
+//! the properties also decide the order blocks are placed in (see [`sort_placements`]), so
+//! torches are not placed before the wall they hang on and sand does not fall before the
+//! blocks under it exist
+//!
+//! # Example
+//!
+//! ```
+//! use mcpi_rs::prelude::*;
+//!
+//! assert!(SAND.properties().gravity);
+//! assert!(TORCH.properties().needs_support);
+//! assert_eq!(GLOWSTONE_BLOCK.properties().light, 15);
+//! assert!(!GLASS.properties().liquid && GLASS.properties().transparent);
+//! ```
+use crate::items::{Block, Item, AIR};
+use crate::position::BlockPos;
+
+/// the physical properties of a block
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlockProperties {
+    /// players and falling blocks collide with it
+    pub solid: bool,
+    /// light and sight go through it
+    pub transparent: bool,
+    /// water or lava, flowing or stationary
+    pub liquid: bool,
+    /// falls when there is nothing below it (sand, gravel, anvil...)
+    pub gravity: bool,
+    /// the light level it emits (0..=15)
+    pub light: u8,
+    /// breaks when the block it stands on or hangs on is missing (torches, flowers, doors...)
+    pub needs_support: bool,
+}
+/// when a block is placed, blocks of an earlier stage are placed first
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Placement {
+    /// blocks that stay where they are placed (including air)
+    Support,
+    /// blocks falling without a block below them
+    Gravity,
+    /// blocks breaking without a block next to or below them
+    Attached,
+    /// water and lava, placed last so they do not flow into the unfinished build
+    Liquid,
+}
+impl BlockProperties {
+    /// get the placement stage of a block with these properties
+    pub fn placement(&self) -> Placement {
+        if self.liquid {
+            Placement::Liquid
+        } else if self.needs_support {
+            Placement::Attached
+        } else if self.gravity {
+            Placement::Gravity
+        } else {
+            Placement::Support
+        }
+    }
+}
+impl Item {
+    /// get the properties of the item, ids without properties (above 255) are solid and opaque
+    pub fn properties(&self) -> BlockProperties {
+        let id = self.clone().unwrap();
+        PROPERTIES
+            .get(id as usize)
+            .copied()
+            .unwrap_or_else(|| properties(id))
+    }
+}
+impl Block {
+    /// get the properties of the block
+    pub fn properties(&self) -> BlockProperties {
+        self.item().properties()
+    }
+    /// get the placement stage of the block
+    pub fn placement(&self) -> Placement {
+        self.properties().placement()
+    }
+}
+/// sort blocks into the order they should be placed in
+///
+/// removals (air) come first, from the top down, so attached blocks are removed before the
+/// blocks holding them; the other blocks are sorted by placement stage (supports, gravity
+/// blocks, attached blocks, liquids), then from the bottom up. The sort is stable, so blocks
+/// at the same height of the same stage keep their order
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let mut blocks = vec![
+///     (BlockPos::new(0, 2, 0), Block::from_item(WATER_STATIONARY)),
+///     (BlockPos::new(0, 3, 0), Block::from_item(TORCH)),
+///     (BlockPos::new(0, 2, 0), Block::from_item(SAND)),
+///     (BlockPos::new(1, 4, 0), Block::from_item(AIR)),
+///     (BlockPos::new(0, 1, 0), Block::from_item(SAND)),
+///     (BlockPos::new(0, 0, 0), Block::from_item(STONE)),
+///     (BlockPos::new(1, 5, 0), Block::from_item(AIR)),
+/// ];
+/// sort_placements(&mut blocks);
+/// let order = blocks.iter().map(|(p, b)| (p.y, b.item())).collect::<Vec<_>>();
+/// assert_eq!(
+///     order,
+///     vec![
+///         (5, AIR),
+///         (4, AIR),
+///         (0, STONE),
+///         (1, SAND),
+///         (2, SAND),
+///         (3, TORCH),
+///         (2, WATER_STATIONARY)
+///     ]
+/// );
+/// ```
+pub fn sort_placements(blocks: &mut [(BlockPos, Block)]) {
+    blocks.sort_by_key(|(pos, block)| {
+        if block.item() == AIR {
+            (false, Placement::Support, -i64::from(pos.y))
+        } else {
+            (true, block.placement(), i64::from(pos.y))
+        }
+    });
+}
+
+/// the properties of every legacy block id
+static PROPERTIES: [BlockProperties; 256] = {
+    let mut table = [BlockProperties {
+        solid: false,
+        transparent: false,
+        liquid: false,
+        gravity: false,
+        light: 0,
+        needs_support: false,
+    }; 256];
+    let mut id = 0;
+    while id < 256 {
+        table[id as usize] = properties(id);
+        id += 1;
+    }
+    table
+};
+const fn properties(id: u32) -> BlockProperties {
+    let liquid = matches!(id, 8..=11);
+    let needs_support = matches!(
+        id,
+        6 | 27 | 28 | 31 | 32 | 37..=40 | 50 | 51 | 55 | 59 | 63 | 64 | 65 | 66 | 68..=72 | 75..=78
+            | 81 | 83 | 92..=94 | 96 | 104..=106 | 111 | 115 | 127 | 131 | 132 | 140..=143
+            | 147..=150 | 157 | 171 | 175..=177 | 193..=197 | 199 | 200 | 207
+    );
+    let passable = matches!(
+        id,
+        0 | 6 | 27 | 28 | 30..=32 | 37..=40 | 50 | 51 | 55 | 59 | 63 | 65 | 66 | 68..=70 | 72
+            | 75..=78 | 83 | 90 | 104..=106 | 115 | 119 | 131 | 132 | 141..=143 | 147 | 148
+            | 157 | 175..=177 | 207 | 209 | 217
+    );
+    let transparent = passable
+        || matches!(
+            id,
+            8 | 9 | 18 | 20 | 26 | 29 | 33 | 34 | 36 | 44 | 52..=54 | 60 | 64 | 67 | 71 | 79 | 81
+                | 85 | 92..=96 | 101 | 102 | 107..=109 | 111 | 113 | 114 | 116..=118 | 120
+                | 122 | 126..=128 | 130 | 134..=136 | 138..=140 | 144..=146 | 149..=151 | 154
+                | 156 | 160 | 161 | 163..=167 | 171 | 178 | 180 | 182..=198 | 200 | 203 | 205
+                | 212
+        );
+    let gravity = matches!(id, 12 | 13 | 122 | 145 | 252);
+    let light = match id {
+        10 | 11 | 51 | 89 | 91 | 119 | 124 | 138 | 169 | 209 => 15,
+        50 | 198 => 14,
+        62 => 13,
+        90 => 11,
+        74 | 94 | 150 => 9,
+        76 | 130 => 7,
+        213 => 3,
+        39 | 117 | 120 | 122 => 1,
+        _ => 0,
+    };
+    BlockProperties {
+        solid: !passable && !liquid,
+        transparent,
+        liquid,
+        gravity,
+        light,
+        needs_support,
+    }
+}
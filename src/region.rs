@@ -0,0 +1,451 @@
+//! The region module of mcpi-rs
+//! `Region`, a cuboid of blocks between two corners (inclusive)
+//!
+//! # Example
+//!
+//! ```
+//! use mcpi_rs::prelude::*;
+//!
+//! let house = Region::new((10, 64, 10), (0, 70, 0));
+//! assert_eq!(house.min(), BlockPos::new(0, 64, 0));
+//! assert_eq!(house.volume(), 11 * 7 * 11);
+//!
+//! // the air inside the walls
+//! let inside = house.shrink(1).unwrap();
+//! assert!(house.contains_region(&inside));
+//! ```
+use crate::position::{Axis, BlockPos};
+use std::fmt::{Display, Formatter};
+
+/// the largest volume `Minecraft::mc_set_region` sets in one `world.setBlocks`
+///
+/// RaspberryJuice places the blocks of a command in one server tick,
+/// so larger commands make the server lag
+pub const MAX_SET_BLOCKS_VOLUME: u64 = 32 * 32 * 32;
+
+/// the order in which the positions of a region are visited
+///
+/// the first axis changes slowest, the last one fastest
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AxisOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+impl AxisOrder {
+    /// the order of `world.getBlocks` (y, then x, then z)
+    pub const SERVER: AxisOrder = AxisOrder::YXZ;
+    /// get the axes from the slowest to the fastest
+    pub fn axes(self) -> [Axis; 3] {
+        match self {
+            AxisOrder::XYZ => [Axis::X, Axis::Y, Axis::Z],
+            AxisOrder::XZY => [Axis::X, Axis::Z, Axis::Y],
+            AxisOrder::YXZ => [Axis::Y, Axis::X, Axis::Z],
+            AxisOrder::YZX => [Axis::Y, Axis::Z, Axis::X],
+            AxisOrder::ZXY => [Axis::Z, Axis::X, Axis::Y],
+            AxisOrder::ZYX => [Axis::Z, Axis::Y, Axis::X],
+        }
+    }
+}
+/// a cuboid between two corners (inclusive), never empty
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Region {
+    min: BlockPos,
+    max: BlockPos,
+}
+impl Region {
+    /// construct the region between two corners, in any order
+    pub fn new(corner1: impl Into<BlockPos>, corner2: impl Into<BlockPos>) -> Region {
+        let (corner1, corner2) = (corner1.into(), corner2.into());
+        Region {
+            min: corner1.min(corner2),
+            max: corner1.max(corner2),
+        }
+    }
+    /// construct the region of a single block
+    pub fn block(pos: impl Into<BlockPos>) -> Region {
+        let pos = pos.into();
+        Region { min: pos, max: pos }
+    }
+    /// get the corner with the smallest coordinates
+    pub fn min(&self) -> BlockPos {
+        self.min
+    }
+    /// get the corner with the largest coordinates
+    pub fn max(&self) -> BlockPos {
+        self.max
+    }
+    /// get the number of blocks along each axis
+    pub fn size(&self) -> BlockPos {
+        self.max - self.min + BlockPos::new(1, 1, 1)
+    }
+    /// get the number of blocks in the region
+    pub fn volume(&self) -> u64 {
+        let size = self.size();
+        size.x as u64 * size.y as u64 * size.z as u64
+    }
+    /// whether the position is inside the region
+    pub fn contains(&self, pos: impl Into<BlockPos>) -> bool {
+        let pos = pos.into();
+        pos.min(self.min) == self.min && pos.max(self.max) == self.max
+    }
+    /// whether the other region is completely inside this one
+    pub fn contains_region(&self, other: &Region) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+    /// whether the regions share at least one block
+    pub fn intersects(&self, other: &Region) -> bool {
+        self.intersection(other).is_some()
+    }
+    /// get the blocks inside both regions (`None` if they do not overlap)
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let a = Region::new((0, 0, 0), (9, 9, 9));
+    /// let b = Region::new((5, 5, 5), (20, 20, 20));
+    /// assert_eq!(a.intersection(&b), Some(Region::new((5, 5, 5), (9, 9, 9))));
+    /// assert_eq!(a.intersection(&Region::block((10, 0, 0))), None);
+    /// ```
+    pub fn intersection(&self, other: &Region) -> Option<Region> {
+        let min = self.min.max(other.min);
+        let max = self.max.min(other.max);
+        if min.x > max.x || min.y > max.y || min.z > max.z {
+            None
+        } else {
+            Some(Region { min, max })
+        }
+    }
+    /// get the smallest region containing both regions
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let a = Region::block((0, 0, 0));
+    /// assert_eq!(a.union(&Region::block((3, -1, 2))), Region::new((0, -1, 0), (3, 0, 2)));
+    /// ```
+    pub fn union(&self, other: &Region) -> Region {
+        Region {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+    /// get the region grown by `n` blocks on every side
+    pub fn expand(&self, n: i32) -> Region {
+        self.expand_by(n, n, n)
+    }
+    /// get the region grown by `dx`, `dy` and `dz` blocks on both sides of each axis
+    ///
+    /// negative values shrink the region, but never below a single block
+    pub fn expand_by(&self, dx: i32, dy: i32, dz: i32) -> Region {
+        let d = BlockPos::new(dx, dy, dz);
+        let (min, max) = (self.min - d, self.max + d);
+        let center = BlockPos::new(
+            self.min.x + (self.max.x - self.min.x) / 2,
+            self.min.y + (self.max.y - self.min.y) / 2,
+            self.min.z + (self.max.z - self.min.z) / 2,
+        );
+        Region {
+            min: min.min(center),
+            max: max.max(center),
+        }
+    }
+    /// get the region shrunk by `n` blocks on every side (`None` if nothing is left)
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let r = Region::new((0, 0, 0), (4, 2, 4));
+    /// assert_eq!(r.shrink(1), Some(Region::new((1, 1, 1), (3, 1, 3))));
+    /// assert_eq!(r.shrink(2), None);
+    /// ```
+    pub fn shrink(&self, n: i32) -> Option<Region> {
+        let d = BlockPos::new(n, n, n);
+        let (min, max) = (self.min + d, self.max - d);
+        if min.x > max.x || min.y > max.y || min.z > max.z {
+            None
+        } else {
+            Some(Region { min, max })
+        }
+    }
+    /// get the region moved by `offset`
+    pub fn translate(&self, offset: impl Into<BlockPos>) -> Region {
+        let offset = offset.into();
+        Region {
+            min: self.min + offset,
+            max: self.max + offset,
+        }
+    }
+    /// iterate over the positions in the order of `world.getBlocks` (y, then x, then z)
+    pub fn positions(&self) -> Positions {
+        self.positions_in(AxisOrder::SERVER)
+    }
+    /// iterate over the positions in a chosen order
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let r = Region::new((0, 0, 0), (1, 1, 0));
+    /// let v = r.positions_in(AxisOrder::XYZ).collect::<Vec<_>>();
+    /// assert_eq!(v[1], BlockPos::new(0, 1, 0));
+    /// let v = r.positions_in(AxisOrder::YXZ).collect::<Vec<_>>();
+    /// assert_eq!(v[1], BlockPos::new(1, 0, 0));
+    /// ```
+    pub fn positions_in(&self, order: AxisOrder) -> Positions {
+        Positions {
+            region: *self,
+            axes: order.axes(),
+            front: 0,
+            back: self.volume(),
+        }
+    }
+    /// split the region into a grid of cubes with `edge` blocks (smaller at the far sides),
+    /// ordered by y, then x, then z
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let r = Region::new((0, 0, 0), (19, 9, 9));
+    /// assert_eq!(
+    ///     r.chunks(16),
+    ///     vec![Region::new((0, 0, 0), (15, 9, 9)), Region::new((16, 0, 0), (19, 9, 9))]
+    /// );
+    /// ```
+    pub fn chunks(&self, edge: i32) -> Vec<Region> {
+        let edge = edge.max(1);
+        let mut chunks = Vec::new();
+        for y in (self.min.y..=self.max.y).step_by(edge as usize) {
+            for x in (self.min.x..=self.max.x).step_by(edge as usize) {
+                for z in (self.min.z..=self.max.z).step_by(edge as usize) {
+                    let min = BlockPos::new(x, y, z);
+                    chunks.push(Region {
+                        min,
+                        max: min.offset(edge - 1, edge - 1, edge - 1).min(self.max),
+                    });
+                }
+            }
+        }
+        chunks
+    }
+    /// split the region into as few regions as possible with at most `max_volume` blocks each
+    /// by halving the longest axis
+    ///
+    /// the regions do not overlap and cover the whole region
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let r = Region::new((0, 0, 0), (99, 9, 9));
+    /// let parts = r.split(MAX_SET_BLOCKS_VOLUME);
+    /// assert!(parts.iter().all(|p| p.volume() <= MAX_SET_BLOCKS_VOLUME));
+    /// assert_eq!(parts.iter().map(Region::volume).sum::<u64>(), r.volume());
+    /// ```
+    pub fn split(&self, max_volume: u64) -> Vec<Region> {
+        let mut parts = Vec::new();
+        self.split_into(max_volume.max(1), &mut parts);
+        parts
+    }
+    fn split_into(&self, max_volume: u64, parts: &mut Vec<Region>) {
+        if self.volume() <= max_volume {
+            parts.push(*self);
+            return;
+        }
+        let size = self.size();
+        let axis = *Axis::ALL
+            .iter()
+            .max_by_key(|a| size.get(**a))
+            .expect("There are three axes");
+        let mid = self.min.get(axis) + size.get(axis) / 2 - 1;
+        Region {
+            min: self.min,
+            max: self.max.with(axis, mid),
+        }
+        .split_into(max_volume, parts);
+        Region {
+            min: self.min.with(axis, mid + 1),
+            max: self.max,
+        }
+        .split_into(max_volume, parts);
+    }
+    /// split the region into slabs with at most `max_volume` blocks each
+    ///
+    /// a slab is a run of whole horizontal layers, or of whole rows (the blocks at one y and x)
+    /// when a layer is too large, or a part of a row when a row is too large
+    ///
+    /// the positions of the slabs in order are the positions of the region in order
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let r = Region::new((0, 0, 0), (9, 3, 9));
+    /// assert_eq!(r.layers(250).len(), 2);
+    ///
+    /// // a layer of 100 blocks is split into rows
+    /// let slabs = r.layers(30);
+    /// assert!(slabs.iter().all(|s| s.volume() <= 30));
+    /// assert!(slabs.iter().flatten().eq(r.positions()));
+    /// ```
+    pub fn layers(&self, max_volume: u64) -> Vec<Region> {
+        let mut slabs = Vec::new();
+        self.runs_into(&AxisOrder::SERVER.axes(), max_volume.max(1), &mut slabs);
+        slabs
+    }
+    /// split along the first axis into runs of whole slices that fit, or slice by slice
+    /// along the next axes
+    fn runs_into(&self, axes: &[Axis], max_volume: u64, slabs: &mut Vec<Region>) {
+        let (axis, rest) = match axes.split_first() {
+            Some(split) => split,
+            None => return slabs.push(*self),
+        };
+        let size = self.size();
+        let slice = rest.iter().map(|a| size.get(*a) as u64).product::<u64>();
+        if slice <= max_volume {
+            let count = (max_volume / slice).min(size.get(*axis) as u64) as i32;
+            for from in (self.min.get(*axis)..=self.max.get(*axis)).step_by(count as usize) {
+                slabs.push(Region {
+                    min: self.min.with(*axis, from),
+                    max: self
+                        .max
+                        .with(*axis, (from + count - 1).min(self.max.get(*axis))),
+                });
+            }
+        } else {
+            for at in self.min.get(*axis)..=self.max.get(*axis) {
+                Region {
+                    min: self.min.with(*axis, at),
+                    max: self.max.with(*axis, at),
+                }
+                .runs_into(rest, max_volume, slabs);
+            }
+        }
+    }
+}
+/// the corners as sent to the server (`x1,y1,z1,x2,y2,z2`)
+impl Display for Region {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.min, self.max)
+    }
+}
+impl<A: Into<BlockPos>, B: Into<BlockPos>> From<(A, B)> for Region {
+    fn from(corners: (A, B)) -> Region {
+        Region::new(corners.0, corners.1)
+    }
+}
+impl IntoIterator for Region {
+    type Item = BlockPos;
+    type IntoIter = Positions;
+    fn into_iter(self) -> Positions {
+        self.positions()
+    }
+}
+impl IntoIterator for &Region {
+    type Item = BlockPos;
+    type IntoIter = Positions;
+    fn into_iter(self) -> Positions {
+        self.positions()
+    }
+}
+/// the positions of a region (see `Region::positions_in`)
+#[derive(Clone, Debug)]
+pub struct Positions {
+    region: Region,
+    axes: [Axis; 3],
+    front: u64,
+    back: u64,
+}
+impl Positions {
+    fn at(&self, mut index: u64) -> BlockPos {
+        let size = self.region.size();
+        let mut pos = self.region.min;
+        for axis in self.axes.iter().rev() {
+            let len = size.get(*axis) as u64;
+            pos = pos.with(*axis, pos.get(*axis) + (index % len) as i32);
+            index /= len;
+        }
+        pos
+    }
+}
+impl Iterator for Positions {
+    type Item = BlockPos;
+    fn next(&mut self) -> Option<BlockPos> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.at(self.front - 1))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+}
+impl DoubleEndedIterator for Positions {
+    fn next_back(&mut self) -> Option<BlockPos> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.at(self.back))
+    }
+}
+impl ExactSizeIterator for Positions {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn split_covers_without_overlap() {
+        let r = Region::new((-3, 60, 5), (17, 71, 12));
+        for max_volume in [0, 1, 7, 64, 100, 1000, r.volume()] {
+            let parts = r.split(max_volume);
+            assert!(parts.iter().all(|p| p.volume() <= max_volume.max(1)));
+            let mut seen = HashSet::new();
+            assert!(parts.iter().flatten().all(|p| seen.insert(p)));
+            assert_eq!(seen.len() as u64, r.volume());
+            assert!(seen.iter().all(|p| r.contains(*p)));
+        }
+        assert_eq!(r.split(r.volume()), vec![r]);
+    }
+
+    #[test]
+    fn layers_keep_the_order() {
+        let r = Region::new((-3, 60, 5), (4, 63, 9));
+        for max_volume in 0..=r.volume() + 1 {
+            let slabs = r.layers(max_volume);
+            assert!(slabs.iter().all(|s| s.volume() <= max_volume.max(1)));
+            assert!(slabs.iter().flatten().eq(r.positions()), "{}", max_volume);
+        }
+    }
+
+    #[test]
+    fn layers_use_the_largest_slabs() {
+        let r = Region::new((0, 0, 0), (3, 5, 2));
+        let sizes = |max_volume| {
+            r.layers(max_volume)
+                .iter()
+                .map(|s| s.size())
+                .collect::<Vec<_>>()
+        };
+        // whole layers of 12 blocks
+        assert_eq!(sizes(r.volume()), vec![r.size()]);
+        assert_eq!(sizes(30), vec![BlockPos::new(4, 2, 3); 3]);
+        // whole rows of 3 blocks
+        let rows = r.layers(7);
+        assert_eq!(rows.len(), 6 * 2);
+        assert_eq!(rows[0], Region::new((0, 0, 0), (1, 0, 2)));
+        assert_eq!(rows[1], Region::new((2, 0, 0), (3, 0, 2)));
+        // parts of rows
+        let parts = r.layers(2);
+        assert_eq!(parts.len(), 6 * 4 * 2);
+        assert_eq!(parts[0], Region::new((0, 0, 0), (0, 0, 1)));
+        assert_eq!(parts[1], Region::new((0, 0, 2), (0, 0, 2)));
+        assert_eq!(r.layers(0).len() as u64, r.volume());
+    }
+}
@@ -0,0 +1,81 @@
+//! The settings module of mcpi-rs
+//! the keys of `world.setting` and `player.setting`
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mcpi_rs::prelude::*;
+//!
+//! let mc = Minecraft::connect().with_dialect(Dialect::PiEdition);
+//!
+//! mc.clone().mc_world_setting(WorldSetting::WorldImmutable, true).unwrap();
+//! mc.mc_player_setting(PlayerSetting::Autojump, false).unwrap();
+//! ```
+use crate::minecraft::Dialect;
+
+/// a setting of the world (`world.setting`)
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WorldSetting {
+    /// whether the players can not change the blocks
+    WorldImmutable,
+    /// whether the name tags of the players are shown
+    NametagsVisible,
+}
+impl WorldSetting {
+    /// get the key sent to the server
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(WorldSetting::NametagsVisible.key(), "nametags_visible");
+    /// ```
+    pub fn key(self) -> &'static str {
+        match self {
+            WorldSetting::WorldImmutable => "world_immutable",
+            WorldSetting::NametagsVisible => "nametags_visible",
+        }
+    }
+    /// whether a dialect supports the setting
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert!(WorldSetting::WorldImmutable.is_supported_by(Dialect::PiEdition));
+    /// assert!(!WorldSetting::WorldImmutable.is_supported_by(Dialect::RaspberryJuice));
+    /// ```
+    pub fn is_supported_by(self, dialect: Dialect) -> bool {
+        match dialect {
+            Dialect::PiEdition => true,
+            Dialect::RaspberryJuice => false,
+        }
+    }
+}
+/// a setting of the player (`player.setting`)
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlayerSetting {
+    /// whether the player jumps up one block automatically
+    Autojump,
+}
+impl PlayerSetting {
+    /// get the key sent to the server
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(PlayerSetting::Autojump.key(), "autojump");
+    /// ```
+    pub fn key(self) -> &'static str {
+        match self {
+            PlayerSetting::Autojump => "autojump",
+        }
+    }
+    /// whether a dialect supports the setting
+    pub fn is_supported_by(self, dialect: Dialect) -> bool {
+        match dialect {
+            Dialect::PiEdition => true,
+            Dialect::RaspberryJuice => false,
+        }
+    }
+}
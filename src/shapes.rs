@@ -0,0 +1,1301 @@
+//! The shapes module of mcpi-rs
+//! the point sets drawn by `McDrawing`
+//!
+//! every function returns the positions of the blocks of a shape, sorted and without
+//! duplicates, so shapes can be combined, filtered or placed with any block before drawing
+//!
+//! the planar shapes lie in the plane through `center` perpendicular to `normal`
+//! (`Axis::Y` is a horizontal shape)
+//!
+//! # Example
+//!
+//! ```
+//! use mcpi_rs::prelude::*;
+//!
+//! let circle = shapes::circle((0, 64, 0), 5, Axis::Y);
+//! assert!(circle.contains(&BlockPos::new(5, 64, 0)));
+//! assert!(circle.iter().all(|p| p.y == 64));
+//!
+//! // a window of glass in a wall of the x-y plane
+//! let window = shapes::disc((10, 70, 3), 2, Axis::Z);
+//! assert!(window.iter().all(|p| p.z == 3));
+//! ```
+use crate::position::{Axis, BlockPos, Vec3};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+/// the points of a segment (use Bresenham’s Algorithm)
+/// see <https://www.geeksforgeeks.org/bresenhams-algorithm-for-3-d-line-drawing/>
+///
+/// unlike the other shapes, the points are ordered from `start` to `end`
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let points = shapes::line((0, 0, 0), (3, 1, 0));
+/// assert_eq!(points.first(), Some(&BlockPos::new(0, 0, 0)));
+/// assert_eq!(points.last(), Some(&BlockPos::new(3, 1, 0)));
+/// assert_eq!(points.len(), 4);
+/// ```
+pub fn line(start: impl Into<BlockPos>, end: impl Into<BlockPos>) -> Vec<BlockPos> {
+    let (mut pos_start, pos_end) = (start.into(), end.into());
+    let mut points: Vec<BlockPos> = vec![pos_start];
+    let (dx, dy, dz) = (
+        (pos_start.x - pos_end.x).abs(),
+        (pos_start.y - pos_end.y).abs(),
+        (pos_start.z - pos_end.z).abs(),
+    );
+    let xs = if pos_end.x > pos_start.x { 1 } else { -1 };
+    let ys = if pos_end.y > pos_start.y { 1 } else { -1 };
+    let zs = if pos_end.z > pos_start.z { 1 } else { -1 };
+    let (mut p1, mut p2) = (2 * dy - dx, 2 * dz - dx);
+    //Driving axis is X-axis
+    if dx >= dy && dx >= dz {
+        while pos_start.x != pos_end.x {
+            pos_start.x += xs;
+            if p1 >= 0 {
+                pos_start.y += ys;
+                p1 -= 2 * dx;
+            }
+            if p2 >= 0 {
+                pos_start.z += zs;
+                p2 -= 2 * dx;
+            }
+            p1 += 2 * dy;
+            p2 += 2 * dz;
+            points.push(pos_start);
+        }
+    }
+    //Driving axis is Y-axis
+    else if dy >= dx && dy >= dz {
+        p1 = 2 * dx - dy;
+        p2 = 2 * dz - dy;
+        while pos_start.y != pos_end.y {
+            pos_start.y += ys;
+            if p1 >= 0 {
+                pos_start.x += xs;
+                p1 -= 2 * dy;
+            }
+            if p2 >= 0 {
+                pos_start.z += zs;
+                p2 -= 2 * dy;
+            }
+            p1 += 2 * dx;
+            p2 += 2 * dz;
+            points.push(pos_start);
+        }
+    } else {
+        //Driving axis is Z-axis
+        p1 = 2 * dy - dz;
+        p2 = 2 * dx - dz;
+        while pos_start.z != pos_end.z {
+            pos_start.z += zs;
+            if p1 >= 0 {
+                pos_start.y += ys;
+                p1 -= 2 * dz;
+            }
+            if p2 >= 0 {
+                pos_start.x += xs;
+                p2 -= 2 * dz;
+            }
+            p1 += 2 * dy;
+            p2 += 2 * dx;
+            points.push(pos_start);
+        }
+    }
+    points
+}
+/// the outline of a circle (use the midpoint circle algorithm)
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let circle = shapes::circle((0, 0, 0), 1, Axis::Y);
+/// // the four neighbours of the center
+/// assert_eq!(circle.len(), 4);
+/// assert!(!circle.contains(&BlockPos::new(0, 0, 0)));
+/// ```
+pub fn circle(center: impl Into<BlockPos>, radius: u32, normal: Axis) -> Vec<BlockPos> {
+    to_plane(center.into(), normal, circle_outline(radius as i32))
+}
+/// a filled circle
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let disc = shapes::disc((0, 0, 0), 3, Axis::X);
+/// // the outline of the disc is the circle
+/// assert!(shapes::circle((0, 0, 0), 3, Axis::X).iter().all(|p| disc.contains(p)));
+/// assert!(disc.contains(&BlockPos::new(0, 1, -1)));
+/// ```
+pub fn disc(center: impl Into<BlockPos>, radius: u32, normal: Axis) -> Vec<BlockPos> {
+    to_plane(center.into(), normal, fill(circle_outline(radius as i32)))
+}
+/// the outline of an ellipse (use the midpoint ellipse algorithm)
+///
+/// `radius_u` and `radius_v` are the radii along the two axes of the plane, in x, y, z order
+/// (x and z for `Axis::Y`, y and z for `Axis::X`, x and y for `Axis::Z`)
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let ellipse = shapes::ellipse((0, 0, 0), 6, 3, Axis::Y);
+/// assert!(ellipse.contains(&BlockPos::new(6, 0, 0)));
+/// assert!(ellipse.contains(&BlockPos::new(0, 0, -3)));
+/// assert_eq!(shapes::ellipse((0, 0, 0), 4, 4, Axis::Y).len(), shapes::circle((0, 0, 0), 4, Axis::Y).len());
+/// ```
+pub fn ellipse(
+    center: impl Into<BlockPos>,
+    radius_u: u32,
+    radius_v: u32,
+    normal: Axis,
+) -> Vec<BlockPos> {
+    to_plane(
+        center.into(),
+        normal,
+        ellipse_outline(radius_u as i32, radius_v as i32),
+    )
+}
+/// a filled ellipse (see [`ellipse`] for the radii)
+pub fn filled_ellipse(
+    center: impl Into<BlockPos>,
+    radius_u: u32,
+    radius_v: u32,
+    normal: Axis,
+) -> Vec<BlockPos> {
+    to_plane(
+        center.into(),
+        normal,
+        fill(ellipse_outline(radius_u as i32, radius_v as i32)),
+    )
+}
+/// a flat ring, the blocks of the disc of `radius` less than `thickness` blocks away from
+/// its edge
+///
+/// the ring has no diagonal gaps, so nothing can pass through it; a thickness larger than
+/// the radius is a whole disc
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let ring = shapes::ring((0, 0, 0), 6, 2, Axis::Y);
+/// assert!(ring.contains(&BlockPos::new(6, 0, 0)));
+/// assert!(ring.contains(&BlockPos::new(5, 0, 0)));
+/// assert!(!ring.contains(&BlockPos::new(4, 0, 0)));
+/// assert_eq!(shapes::ring((0, 0, 0), 3, 4, Axis::Y), shapes::disc((0, 0, 0), 3, Axis::Y));
+/// ```
+pub fn ring(
+    center: impl Into<BlockPos>,
+    radius: u32,
+    thickness: u32,
+    normal: Axis,
+) -> Vec<BlockPos> {
+    let disc = fill(circle_outline(radius as i32))
+        .into_iter()
+        .map(|(u, v)| BlockPos::new(u, v, 0))
+        .collect();
+    let points = shell(disc, thickness, &neighbors(false))
+        .into_iter()
+        .map(|p| (p.x, p.y))
+        .collect();
+    to_plane(center.into(), normal, points)
+}
+
+/// a half of a round shape, cut through its center across an axis
+///
+/// both halves keep the blocks of the cut, so a dome and a bowl make a whole sphere
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Half {
+    /// the half towards the positive end of the axis (a dome for `Axis::Y`)
+    Upper,
+    /// the half towards the negative end of the axis (a bowl for `Axis::Y`)
+    Lower,
+}
+/// a filled sphere
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let sphere = shapes::sphere((0, 64, 0), 4);
+/// assert!(sphere.contains(&BlockPos::new(0, 68, 0)));
+/// assert!(!sphere.contains(&BlockPos::new(0, 69, 0)));
+/// assert!(!sphere.contains(&BlockPos::new(3, 67, 3)));
+/// ```
+pub fn sphere(center: impl Into<BlockPos>, radius: u32) -> Vec<BlockPos> {
+    ellipsoid(center, (radius, radius, radius))
+}
+/// a hollow sphere, the blocks of the sphere less than `thickness` blocks away from its
+/// surface
+///
+/// the shell is watertight: no block inside it touches the outside, not even diagonally
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let shell = shapes::hollow_sphere((0, 0, 0), 6, 1);
+/// assert!(shell.contains(&BlockPos::new(6, 0, 0)));
+/// assert!(!shell.contains(&BlockPos::new(0, 0, 0)));
+/// assert!(shell.len() < shapes::sphere((0, 0, 0), 6).len());
+/// ```
+pub fn hollow_sphere(center: impl Into<BlockPos>, radius: u32, thickness: u32) -> Vec<BlockPos> {
+    hollow_ellipsoid(center, (radius, radius, radius), thickness)
+}
+/// a filled ellipsoid with the radii along x, y and z
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// // a flattened planet
+/// let planet = shapes::ellipsoid((0, 0, 0), (8, 4, 8));
+/// assert!(planet.contains(&BlockPos::new(8, 0, 0)));
+/// assert!(planet.contains(&BlockPos::new(0, 4, 0)));
+/// assert!(!planet.contains(&BlockPos::new(0, 5, 0)));
+/// ```
+pub fn ellipsoid(center: impl Into<BlockPos>, radii: (u32, u32, u32)) -> Vec<BlockPos> {
+    let center = center.into();
+    ellipsoid_points(radii)
+        .into_iter()
+        .map(|p| center + p)
+        .collect()
+}
+/// a hollow ellipsoid, watertight like [`hollow_sphere`]
+pub fn hollow_ellipsoid(
+    center: impl Into<BlockPos>,
+    radii: (u32, u32, u32),
+    thickness: u32,
+) -> Vec<BlockPos> {
+    let center = center.into();
+    shell(ellipsoid_points(radii), thickness, &neighbors(true))
+        .into_iter()
+        .map(|p| center + p)
+        .collect()
+}
+/// keep the half of a shape on one side of the plane through `center` across `axis`
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let center = BlockPos::new(0, 64, 0);
+/// let dome = shapes::half(shapes::hollow_sphere(center, 10, 1), center, Axis::Y, Half::Upper);
+/// assert!(dome.iter().all(|p| p.y >= 64));
+/// assert!(dome.contains(&BlockPos::new(0, 74, 0)));
+/// assert!(dome.contains(&BlockPos::new(10, 64, 0)));
+/// ```
+pub fn half(
+    points: impl IntoIterator<Item = BlockPos>,
+    center: impl Into<BlockPos>,
+    axis: Axis,
+    half: Half,
+) -> Vec<BlockPos> {
+    let cut = center.into().get(axis);
+    points
+        .into_iter()
+        .filter(|p| match half {
+            Half::Upper => p.get(axis) >= cut,
+            Half::Lower => p.get(axis) <= cut,
+        })
+        .collect()
+}
+
+/// a filled cylinder around the axis from `start` to `end`, in any direction
+///
+/// the cylinder is a frustum with the same radius at both ends; a cylinder with
+/// `start == end` is a horizontal disc
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let tower = shapes::cylinder((0, 64, 0), (0, 80, 0), 3);
+/// assert!(tower.contains(&BlockPos::new(3, 80, 0)));
+/// assert!(!tower.contains(&BlockPos::new(0, 81, 0)));
+///
+/// // a diagonal beam
+/// let beam = shapes::cylinder((0, 0, 0), (20, 10, -5), 1);
+/// assert!(shapes::line((0, 0, 0), (20, 10, -5)).iter().all(|p| beam.contains(p)));
+/// ```
+pub fn cylinder(
+    start: impl Into<BlockPos>,
+    end: impl Into<BlockPos>,
+    radius: u32,
+) -> Vec<BlockPos> {
+    frustum(start, end, radius, radius)
+}
+/// a hollow cylinder, a pipe open at both ends with a wall `thickness` blocks thick
+///
+/// the wall is watertight: no block inside the pipe touches the outside through it, not even
+/// diagonally
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let pipe = shapes::hollow_cylinder((0, 0, 0), (30, 0, 0), 4, 1);
+/// assert!(pipe.contains(&BlockPos::new(0, 4, 0)));
+/// assert!(!pipe.contains(&BlockPos::new(0, 0, 0)));
+/// assert!(!pipe.contains(&BlockPos::new(15, 0, 0)));
+/// ```
+pub fn hollow_cylinder(
+    start: impl Into<BlockPos>,
+    end: impl Into<BlockPos>,
+    radius: u32,
+    thickness: u32,
+) -> Vec<BlockPos> {
+    hollow_frustum(start, end, radius, radius, thickness)
+}
+/// a filled cone with its base around `start` and its tip at `end`
+///
+/// if `start == end` the cone is a horizontal [`disc`] of `radius`
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let spire = shapes::cone((0, 80, 0), (0, 90, 0), 4);
+/// assert!(spire.contains(&BlockPos::new(4, 80, 0)));
+/// assert!(spire.contains(&BlockPos::new(0, 90, 0)));
+/// assert!(!spire.contains(&BlockPos::new(1, 90, 0)));
+/// ```
+pub fn cone(start: impl Into<BlockPos>, end: impl Into<BlockPos>, radius: u32) -> Vec<BlockPos> {
+    frustum(start, end, radius, 0)
+}
+/// a hollow cone, open at its base (see [`hollow_cylinder`])
+pub fn hollow_cone(
+    start: impl Into<BlockPos>,
+    end: impl Into<BlockPos>,
+    radius: u32,
+    thickness: u32,
+) -> Vec<BlockPos> {
+    hollow_frustum(start, end, radius, 0, thickness)
+}
+/// a filled truncated cone from the radius `radius_start` around `start` to `radius_end`
+/// around `end`
+///
+/// the axis is traversed with the points of [`line()`]; a block belongs to the frustum if its
+/// center is between the ends and less than half a block outside of the radius there
+///
+/// if `start == end` the frustum is a horizontal [`disc`] of `radius_start` and `radius_end`
+/// is ignored
+pub fn frustum(
+    start: impl Into<BlockPos>,
+    end: impl Into<BlockPos>,
+    radius_start: u32,
+    radius_end: u32,
+) -> Vec<BlockPos> {
+    frustum_points(start.into(), end.into(), radius_start, radius_end, 0)
+        .into_iter()
+        .collect()
+}
+/// a hollow truncated cone, open at both ends (see [`hollow_cylinder`])
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// // a cooling tower
+/// let tower = shapes::hollow_frustum((0, 64, 0), (0, 90, 0), 12, 8, 1);
+/// assert!(tower.contains(&BlockPos::new(12, 64, 0)));
+/// assert!(tower.contains(&BlockPos::new(8, 90, 0)));
+/// assert!(!tower.contains(&BlockPos::new(0, 64, 0)));
+/// ```
+pub fn hollow_frustum(
+    start: impl Into<BlockPos>,
+    end: impl Into<BlockPos>,
+    radius_start: u32,
+    radius_end: u32,
+    thickness: u32,
+) -> Vec<BlockPos> {
+    let (start, end) = (start.into(), end.into());
+    let solid = frustum_points(start, end, radius_start, radius_end, 0);
+    // the frustum goes on past its ends, so only its side counts as outside
+    let extended = frustum_points(start, end, radius_start, radius_end, thickness + 1);
+    shell(extended, thickness, &neighbors(true))
+        .into_iter()
+        .filter(|p| solid.contains(p))
+        .collect()
+}
+
+/// a filled torus around `center`, with the hole along `normal`
+///
+/// `major_radius` is the distance from the center to the middle of the tube, `minor_radius`
+/// the radius of the tube
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let donut = shapes::torus((0, 64, 0), 8, 2, Axis::Y);
+/// assert!(donut.contains(&BlockPos::new(10, 64, 0)));
+/// assert!(donut.contains(&BlockPos::new(8, 66, 0)));
+/// assert!(!donut.contains(&BlockPos::new(0, 64, 0)));
+/// assert!(!donut.contains(&BlockPos::new(5, 64, 0)));
+/// ```
+pub fn torus(
+    center: impl Into<BlockPos>,
+    major_radius: u32,
+    minor_radius: u32,
+    normal: Axis,
+) -> Vec<BlockPos> {
+    let center = center.into();
+    let (u, v) = plane_axes(normal);
+    let (major, minor) = (major_radius as f64, minor_radius as f64);
+    let (reach, depth) = ((major_radius + minor_radius) as i32, minor_radius as i32);
+    let mut points = BTreeSet::new();
+    for du in -reach..=reach {
+        for dv in -reach..=reach {
+            let tube = ((du * du + dv * dv) as f64).sqrt() - major;
+            for dw in -depth..=depth {
+                if tube * tube + f64::from(dw * dw) < (minor + 0.5).powi(2) {
+                    points.insert(along(along(along(center, u, du), v, dv), normal, dw));
+                }
+            }
+        }
+    }
+    points.into_iter().collect()
+}
+/// a filled square pyramid standing on `base_center`, `height` layers high
+///
+/// the base is a square of `2 * half_width + 1` blocks and the layers shrink evenly up to
+/// the top layer, a single block; with a `height` of `half_width + 1` every layer is one
+/// block smaller on each side than the one below it
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let pyramid = shapes::pyramid((0, 64, 0), 4, 5);
+/// assert!(pyramid.contains(&BlockPos::new(4, 64, -4)));
+/// assert!(pyramid.contains(&BlockPos::new(3, 65, 3)));
+/// assert!(!pyramid.contains(&BlockPos::new(4, 65, 0)));
+/// assert!(pyramid.iter().filter(|p| p.y == 68).eq(&[BlockPos::new(0, 68, 0)]));
+///
+/// // a flatter pyramid
+/// assert_eq!(shapes::pyramid((0, 0, 0), 8, 3).iter().map(|p| p.y).max(), Some(2));
+/// ```
+pub fn pyramid(base_center: impl Into<BlockPos>, half_width: u32, height: u32) -> Vec<BlockPos> {
+    pyramid_points(base_center.into(), half_width, height)
+        .into_iter()
+        .collect()
+}
+/// a hollow square pyramid, closed at its base, with walls `thickness` blocks thick
+///
+/// the walls are watertight like the ones of [`hollow_sphere`]
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let pyramid = shapes::hollow_pyramid((0, 64, 0), 10, 11, 1);
+/// assert!(pyramid.contains(&BlockPos::new(10, 64, 10)));
+/// assert!(pyramid.contains(&BlockPos::new(0, 64, 0)));
+/// assert!(!pyramid.contains(&BlockPos::new(0, 65, 0)));
+/// ```
+pub fn hollow_pyramid(
+    base_center: impl Into<BlockPos>,
+    half_width: u32,
+    height: u32,
+    thickness: u32,
+) -> Vec<BlockPos> {
+    let solid = pyramid_points(base_center.into(), half_width, height);
+    shell(solid, thickness, &neighbors(true))
+}
+/// a stepped pyramid (a ziggurat) standing on `base_center`
+///
+/// every step is `step_height` layers high and `step_width` blocks narrower on each side
+/// than the step below it, up to the last step that still fits; a step of no width or no
+/// height makes no pyramid
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let ziggurat = shapes::stepped_pyramid((0, 64, 0), 10, 3, 2);
+/// assert!(ziggurat.contains(&BlockPos::new(10, 65, 10)));
+/// assert!(!ziggurat.contains(&BlockPos::new(10, 66, 10)));
+/// assert!(ziggurat.contains(&BlockPos::new(7, 66, 7)));
+/// // steps of 10, 7, 4 and 1 blocks
+/// assert_eq!(ziggurat.iter().map(|p| p.y).max(), Some(71));
+/// ```
+pub fn stepped_pyramid(
+    base_center: impl Into<BlockPos>,
+    half_width: u32,
+    step_width: u32,
+    step_height: u32,
+) -> Vec<BlockPos> {
+    if step_width == 0 || step_height == 0 {
+        return Vec::new();
+    }
+    let base_center = base_center.into();
+    let steps = half_width / step_width + 1;
+    let mut points = BTreeSet::new();
+    for layer in 0..steps * step_height {
+        let half_width = (half_width - layer / step_height * step_width) as i32;
+        points.extend(square(base_center.offset(0, layer as i32, 0), half_width));
+    }
+    points.into_iter().collect()
+}
+/// a filled prism of a regular polygon with `sides` corners, `length` blocks long from
+/// `base_center` towards the positive end of `axis`
+///
+/// `radius` is the distance from the center of the polygon to its corners; one corner
+/// points up (+y) for a horizontal prism, and towards +z for a vertical one
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// // the triangular roof of a house 12 blocks deep
+/// let roof = shapes::prism((0, 70, 0), 4, 3, 12, Axis::Z);
+/// assert!(roof.contains(&BlockPos::new(0, 74, 11)));
+/// assert!(!roof.contains(&BlockPos::new(0, 74, 12)));
+/// assert!(!roof.contains(&BlockPos::new(1, 74, 0)));
+///
+/// // a hexagonal column
+/// let column = shapes::prism((0, 64, 0), 3, 6, 10, Axis::Y);
+/// assert!(column.contains(&BlockPos::new(0, 73, 3)));
+/// assert!(column.iter().all(|p| (64..74).contains(&p.y)));
+/// ```
+pub fn prism(
+    base_center: impl Into<BlockPos>,
+    radius: u32,
+    sides: u32,
+    length: u32,
+    axis: Axis,
+) -> Vec<BlockPos> {
+    if sides < 3 {
+        return Vec::new();
+    }
+    // `up` is the axis of the plane the first corner points along
+    let (side, up) = match axis {
+        Axis::X => (Axis::Z, Axis::Y),
+        Axis::Y => (Axis::X, Axis::Z),
+        Axis::Z => (Axis::X, Axis::Y),
+    };
+    let n = sides as f64;
+    let apothem = radius as f64 * (std::f64::consts::PI / n).cos();
+    let normals = (0..sides)
+        .map(|k| {
+            let angle = std::f64::consts::FRAC_PI_2 + (2 * k + 1) as f64 * std::f64::consts::PI / n;
+            (angle.cos(), angle.sin())
+        })
+        .collect::<Vec<_>>();
+    let base_center = base_center.into();
+    let reach = radius as i32;
+    let mut polygon = Vec::new();
+    for a in -reach..=reach {
+        for b in -reach..=reach {
+            let inside = normals
+                .iter()
+                .all(|(na, nb)| a as f64 * na + b as f64 * nb < apothem + 0.5 - 1e-9);
+            if inside {
+                polygon.push(along(along(base_center, side, a), up, b));
+            }
+        }
+    }
+    extrude(polygon, axis, length)
+}
+/// the shape of an arch, see [`arch`]
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ArchStyle {
+    /// a half circle
+    Round,
+    /// two arcs meeting at a point (an equilateral gothic arch)
+    Pointed,
+}
+/// a flat arch springing from the height of `base_center`, in the vertical plane
+/// perpendicular to `normal` (`Axis::Y` is taken as `Axis::Z`)
+///
+/// `radius` is half the width of the opening under the arch and `thickness` the width of
+/// the band of blocks around it; like [`ring`], the arch has no diagonal gaps. A pointed arch
+/// is made of two arcs of the width of the opening, centered on its opposite sides
+///
+/// an arch is one block deep, see [`extrude`] for a deeper one
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let round = shapes::arch((0, 64, 0), 4, 1, Axis::Z, ArchStyle::Round);
+/// assert!(round.contains(&BlockPos::new(0, 69, 0)));
+/// assert!(round.contains(&BlockPos::new(5, 64, 0)));
+/// assert!(!round.contains(&BlockPos::new(0, 68, 0)));
+/// assert!(round.iter().all(|p| p.y >= 64 && p.z == 0));
+///
+/// // a pointed arch is higher than a round one of the same width
+/// let pointed = shapes::arch((0, 64, 0), 4, 1, Axis::Z, ArchStyle::Pointed);
+/// assert!(pointed.contains(&BlockPos::new(0, 72, 0)));
+///
+/// // a gate through a wall 3 blocks deep
+/// let gate = shapes::extrude(pointed, Axis::Z, 3);
+/// assert!(gate.contains(&BlockPos::new(0, 72, 2)));
+/// ```
+pub fn arch(
+    base_center: impl Into<BlockPos>,
+    radius: u32,
+    thickness: u32,
+    normal: Axis,
+    style: ArchStyle,
+) -> Vec<BlockPos> {
+    let (r, outer) = (radius as i32, (radius + thickness) as i32);
+    let mut solid = BTreeSet::new();
+    match style {
+        ArchStyle::Round => solid.extend(
+            fill(circle_outline(outer))
+                .into_iter()
+                .map(|(u, v)| BlockPos::new(u, v, 0)),
+        ),
+        ArchStyle::Pointed => {
+            // the outer edge is made of the arcs of the opening, widened by `thickness`
+            let span = (2 * radius + thickness) as f64 + 0.5;
+            let reach = (span * span - (r * r) as f64).sqrt() as i32 + 1;
+            for u in -outer..=outer {
+                for v in -reach..=reach {
+                    let d = |c: i32| ((u - c).pow(2) + v * v) as f64;
+                    if d(r) < span * span && d(-r) < span * span {
+                        solid.insert(BlockPos::new(u, v, 0));
+                    }
+                }
+            }
+        }
+    }
+    let normal = if normal == Axis::Y { Axis::Z } else { normal };
+    let points = shell(solid, thickness, &neighbors(false))
+        .into_iter()
+        .filter(|p| p.y >= 0)
+        .map(|p| match normal {
+            Axis::X => (p.y, p.x),
+            _ => (p.x, p.y),
+        })
+        .collect();
+    to_plane(base_center.into(), normal, points)
+}
+/// copies of `points` stacked `length` times towards the positive end of `axis`, the first
+/// copy in place
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let wall = shapes::extrude(shapes::line((0, 64, 0), (10, 64, 0)), Axis::Y, 4);
+/// assert_eq!(wall.len(), 44);
+/// assert!(wall.contains(&BlockPos::new(10, 67, 0)));
+/// ```
+pub fn extrude(
+    points: impl IntoIterator<Item = BlockPos>,
+    axis: Axis,
+    length: u32,
+) -> Vec<BlockPos> {
+    let mut extruded = BTreeSet::new();
+    for p in points {
+        extruded.extend((0..length as i32).map(|d| along(p, axis, d)));
+    }
+    extruded.into_iter().collect()
+}
+
+/// a filled triangle between three points, in any plane
+///
+/// the triangle is a [`polygon`] of three vertices: its edges are the [`line()`]s between the
+/// vertices, so it has no gaps along them, even where it is thinner than a block
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let (a, b, c) = (BlockPos::new(0, 64, 0), BlockPos::new(10, 64, 0), BlockPos::new(0, 74, 10));
+/// let face = shapes::triangle(a, b, c);
+/// assert!(face.contains(&a) && face.contains(&b) && face.contains(&c));
+/// assert!(face.contains(&BlockPos::new(2, 66, 2)));
+/// assert!(!face.contains(&BlockPos::new(8, 72, 8)));
+/// // a block for every x and z under the sloped face
+/// assert_eq!(face.len(), 66);
+/// ```
+pub fn triangle(
+    a: impl Into<BlockPos>,
+    b: impl Into<BlockPos>,
+    c: impl Into<BlockPos>,
+) -> Vec<BlockPos> {
+    polygon(&[a.into(), b.into(), c.into()], &[])
+}
+/// the edges of a triangle, a wireframe
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// let edges = shapes::triangle_outline((0, 0, 0), (4, 0, 0), (0, 4, 0));
+/// assert_eq!(edges.len(), 12);
+/// assert!(!edges.contains(&BlockPos::new(1, 1, 0)));
+/// ```
+pub fn triangle_outline(
+    a: impl Into<BlockPos>,
+    b: impl Into<BlockPos>,
+    c: impl Into<BlockPos>,
+) -> Vec<BlockPos> {
+    polygon_outline(&[a.into(), b.into(), c.into()])
+}
+/// the edges of a polygon, the [`line()`]s from each vertex to the next one and from the last
+/// vertex back to the first one
+pub fn polygon_outline(vertices: &[BlockPos]) -> Vec<BlockPos> {
+    let mut points = BTreeSet::new();
+    for (i, &start) in vertices.iter().enumerate() {
+        points.extend(line(start, vertices[(i + 1) % vertices.len()]));
+    }
+    points.into_iter().collect()
+}
+/// a filled planar polygon with the corners `vertices` and the polygons `holes` cut out of
+/// it, like the faces of the python `minecraftstuff` (`drawFace`)
+///
+/// the polygon may be concave and lie in any plane; the blocks are the ones of the plane
+/// through the vertices whose centers are inside the polygon, seen along the axis the plane
+/// faces the most, plus the edges of the polygon and of its holes (see [`polygon_outline`]).
+/// Vertices that are not in a plane are filled in the plane that fits them best
+/// # Example
+/// ```
+/// use mcpi_rs::prelude::*;
+///
+/// // a square window frame
+/// let square = [
+///     BlockPos::new(0, 64, 0),
+///     BlockPos::new(10, 64, 0),
+///     BlockPos::new(10, 74, 0),
+///     BlockPos::new(0, 74, 0),
+/// ];
+/// let hole = vec![
+///     BlockPos::new(3, 67, 0),
+///     BlockPos::new(7, 67, 0),
+///     BlockPos::new(7, 71, 0),
+///     BlockPos::new(3, 71, 0),
+/// ];
+/// let frame = shapes::polygon(&square, &[hole]);
+/// assert!(frame.contains(&BlockPos::new(1, 65, 0)));
+/// assert!(frame.contains(&BlockPos::new(3, 67, 0)));
+/// assert!(!frame.contains(&BlockPos::new(5, 69, 0)));
+/// assert_eq!(frame.len(), 121 - 9);
+///
+/// // an L-shaped floor
+/// let floor = shapes::polygon(
+///     &[
+///         BlockPos::new(0, 64, 0),
+///         BlockPos::new(8, 64, 0),
+///         BlockPos::new(8, 64, 3),
+///         BlockPos::new(3, 64, 3),
+///         BlockPos::new(3, 64, 8),
+///         BlockPos::new(0, 64, 8),
+///     ],
+///     &[],
+/// );
+/// assert!(floor.contains(&BlockPos::new(1, 64, 7)));
+/// assert!(!floor.contains(&BlockPos::new(6, 64, 6)));
+/// ```
+pub fn polygon(vertices: &[BlockPos], holes: &[Vec<BlockPos>]) -> Vec<BlockPos> {
+    let mut points = polygon_outline(vertices)
+        .into_iter()
+        .chain(holes.iter().flat_map(|hole| polygon_outline(hole)))
+        .collect::<BTreeSet<_>>();
+    // the normal of the plane (Newell's method) and its center
+    let vertex = |i: usize| vertices[i % vertices.len()].to_vec3();
+    let normal = (0..vertices.len())
+        .map(|i| vertex(i).cross(vertex(i + 1)))
+        .fold(Vec3::ZERO, |n, c| n + c);
+    let center =
+        vertices.iter().fold(Vec3::ZERO, |c, v| c + v.to_vec3()) / vertices.len().max(1) as f64;
+    let axis = [Axis::X, Axis::Y, Axis::Z]
+        .iter()
+        .copied()
+        .max_by(|&a, &b| {
+            component(normal, a)
+                .abs()
+                .total_cmp(&component(normal, b).abs())
+        })
+        .unwrap_or(Axis::Y);
+    if component(normal, axis).abs() < 1e-9 {
+        // the vertices are on a line
+        return points.into_iter().collect();
+    }
+    let (u, v) = plane_axes(axis);
+    let project = |ring: &[BlockPos]| {
+        ring.iter()
+            .map(|p| (p.get(u) as f64, p.get(v) as f64))
+            .collect::<Vec<_>>()
+    };
+    let rings = std::iter::once(project(vertices))
+        .chain(holes.iter().map(|hole| project(hole)))
+        .collect::<Vec<_>>();
+    let (min, max) = vertices
+        .iter()
+        .fold((vertices[0], vertices[0]), |(min, max), &p| {
+            (min.min(p), max.max(p))
+        });
+    for pu in min.get(u)..=max.get(u) {
+        for pv in min.get(v)..=max.get(v) {
+            let (x, y) = (pu as f64, pv as f64);
+            // even-odd rule: the inside of a hole is outside of the polygon
+            let mut inside = false;
+            for ring in &rings {
+                for (i, &(x0, y0)) in ring.iter().enumerate() {
+                    let (x1, y1) = ring[(i + 1) % ring.len()];
+                    if (y0 > y) != (y1 > y) && x < x0 + (y - y0) / (y1 - y0) * (x1 - x0) {
+                        inside = !inside;
+                    }
+                }
+            }
+            if inside {
+                let w = component(center, axis)
+                    - (component(normal, u) * (x - component(center, u))
+                        + component(normal, v) * (y - component(center, v)))
+                        / component(normal, axis);
+                let p = BlockPos::default().with(u, pu).with(v, pv);
+                points.insert(p.with(axis, w.round() as i32));
+            }
+        }
+    }
+    points.into_iter().collect()
+}
+
+/// the two axes spanning the plane perpendicular to `normal`, in x, y, z order
+fn plane_axes(normal: Axis) -> (Axis, Axis) {
+    match normal {
+        Axis::X => (Axis::Y, Axis::Z),
+        Axis::Y => (Axis::X, Axis::Z),
+        Axis::Z => (Axis::X, Axis::Y),
+    }
+}
+/// place the points `(u, v)` of a plane on the plane through `center`
+fn to_plane(center: BlockPos, normal: Axis, points: Vec<(i32, i32)>) -> Vec<BlockPos> {
+    let (u, v) = plane_axes(normal);
+    let points = points
+        .into_iter()
+        .map(|(du, dv)| {
+            center
+                .with(u, center.get(u) + du)
+                .with(v, center.get(v) + dv)
+        })
+        .collect::<BTreeSet<_>>();
+    points.into_iter().collect()
+}
+/// the offsets of the 8 neighbours in the x-y plane, or of the 26 neighbours in space
+fn neighbors(space: bool) -> Vec<BlockPos> {
+    let dz = if space { -1..=1 } else { 0..=0 };
+    let mut offsets = Vec::new();
+    for x in -1..=1 {
+        for y in -1..=1 {
+            for z in dz.clone() {
+                if (x, y, z) != (0, 0, 0) {
+                    offsets.push(BlockPos::new(x, y, z));
+                }
+            }
+        }
+    }
+    offsets
+}
+/// the coordinate of `v` along `axis`
+fn component(v: Vec3, axis: Axis) -> f64 {
+    match axis {
+        Axis::X => v.x,
+        Axis::Y => v.y,
+        Axis::Z => v.z,
+    }
+}
+/// move `p` by `d` blocks along `axis`
+fn along(p: BlockPos, axis: Axis, d: i32) -> BlockPos {
+    p.with(axis, p.get(axis) + d)
+}
+/// the horizontal square of `2 * half_width + 1` blocks around `center`
+fn square(center: BlockPos, half_width: i32) -> impl Iterator<Item = BlockPos> {
+    (-half_width..=half_width)
+        .flat_map(move |x| (-half_width..=half_width).map(move |z| center.offset(x, 0, z)))
+}
+/// the blocks of the square pyramid standing on `base_center`
+fn pyramid_points(base_center: BlockPos, half_width: u32, height: u32) -> BTreeSet<BlockPos> {
+    let mut points = BTreeSet::new();
+    for layer in 0..height {
+        let half_width = if height == 1 {
+            half_width as i32
+        } else {
+            let left = (height - 1 - layer) as f64 / (height - 1) as f64;
+            (half_width as f64 * left).round() as i32
+        };
+        points.extend(square(base_center.offset(0, layer as i32, 0), half_width));
+    }
+    points
+}
+/// the points of `solid` less than `thickness` steps away from the outside of it
+///
+/// a step goes to any of the `offsets`, diagonals included, so no point of the inside
+/// touches the outside, not even diagonally
+fn shell(solid: BTreeSet<BlockPos>, thickness: u32, offsets: &[BlockPos]) -> Vec<BlockPos> {
+    if thickness == 0 {
+        return Vec::new();
+    }
+    let lookup = solid.iter().copied().collect::<HashSet<_>>();
+    let mut layer = solid
+        .iter()
+        .filter(|&&p| offsets.iter().any(|&o| !lookup.contains(&(p + o))))
+        .copied()
+        .collect::<Vec<_>>();
+    let mut shell = layer.iter().copied().collect::<BTreeSet<_>>();
+    for _ in 1..thickness {
+        let mut next = Vec::new();
+        for p in layer {
+            for &o in offsets {
+                if lookup.contains(&(p + o)) && shell.insert(p + o) {
+                    next.push(p + o);
+                }
+            }
+        }
+        layer = next;
+    }
+    shell.into_iter().collect()
+}
+/// the blocks of the frustum from `start` to `end`, continued `extend` blocks past both ends
+/// with the radius of the end
+fn frustum_points(
+    start: BlockPos,
+    end: BlockPos,
+    radius_start: u32,
+    radius_end: u32,
+    extend: u32,
+) -> BTreeSet<BlockPos> {
+    if start == end {
+        return disc(start, radius_start, Axis::Y).into_iter().collect();
+    }
+    let (s, d) = (start.to_vec3(), (end - start).to_vec3());
+    let length = d.length();
+    let (r0, r1) = (radius_start as f64, radius_end as f64);
+    let max_r = radius_start.max(radius_end) as i32 + 1;
+    // the end caps lean up to `max_r` blocks along the driving axis, at least `length / √3`
+    let e = extend as f64 / length;
+    let over = e + max_r as f64 * 3f64.sqrt() / length;
+    let (first, last) = ((s - d * over).round(), (s + d * (1.0 + over)).round());
+    // every point of the line is one step along its driving axis, so each plane across that
+    // axis is scanned once
+    let (dx, dy, dz) = (
+        (last.x - first.x).abs(),
+        (last.y - first.y).abs(),
+        (last.z - first.z).abs(),
+    );
+    let axis = if dx >= dy && dx >= dz {
+        Axis::X
+    } else if dy >= dz {
+        Axis::Y
+    } else {
+        Axis::Z
+    };
+    let (u, v) = plane_axes(axis);
+    // a plane across the axis cuts the frustum in an ellipse up to `length / step` times wider
+    // than the radius
+    let step = component(d, axis).abs();
+    let reach = (max_r as f64 * length / step).ceil() as i32 + 1;
+    let mut points = BTreeSet::new();
+    for a in line(first, last) {
+        for du in -reach..=reach {
+            for dv in -reach..=reach {
+                let p = a.with(u, a.get(u) + du).with(v, a.get(v) + dv);
+                let w = p.to_vec3() - s;
+                let t = w.dot(d) / (length * length);
+                if t < -e - 1e-9 || t > 1.0 + e + 1e-9 {
+                    continue;
+                }
+                let r = r0 + (r1 - r0) * t.clamp(0.0, 1.0);
+                if w.distance_squared(d * t) < (r + 0.5).powi(2) {
+                    points.insert(p);
+                }
+            }
+        }
+    }
+    points
+}
+/// the blocks of the ellipsoid around the origin, the blocks whose centers are less than
+/// half a block outside of the radii, like the midpoint circle
+fn ellipsoid_points(radii: (u32, u32, u32)) -> BTreeSet<BlockPos> {
+    let (a, b, c) = (radii.0 as i32, radii.1 as i32, radii.2 as i32);
+    let scale = |n: i32, r: i32| (n as f64 / (r as f64 + 0.5)).powi(2);
+    let mut points = BTreeSet::new();
+    for x in -a..=a {
+        for y in -b..=b {
+            for z in -c..=c {
+                if scale(x, a) + scale(y, b) + scale(z, c) < 1.0 {
+                    points.insert(BlockPos::new(x, y, z));
+                }
+            }
+        }
+    }
+    points
+}
+/// fill the spans between the points of a symmetric outline
+fn fill(outline: Vec<(i32, i32)>) -> Vec<(i32, i32)> {
+    let mut spans = BTreeMap::new();
+    for (u, v) in outline {
+        let max = spans.entry(u).or_insert(0);
+        *max = v.abs().max(*max);
+    }
+    spans
+        .into_iter()
+        .flat_map(|(u, max)| (-max..=max).map(move |v| (u, v)))
+        .collect()
+}
+/// the midpoint circle around the origin
+fn circle_outline(radius: i32) -> Vec<(i32, i32)> {
+    let mut points = Vec::new();
+    let (mut x, mut y, mut d) = (radius, 0, 1 - radius);
+    while y <= x {
+        for &(u, v) in &[(x, y), (y, x)] {
+            points.extend_from_slice(&[(u, v), (-u, v), (u, -v), (-u, -v)]);
+        }
+        y += 1;
+        if d < 0 {
+            d += 2 * y + 1;
+        } else {
+            x -= 1;
+            d += 2 * (y - x) + 1;
+        }
+    }
+    points
+}
+/// the midpoint ellipse around the origin with the radii `a` (along u) and `b` (along v)
+fn ellipse_outline(a: i32, b: i32) -> Vec<(i32, i32)> {
+    if a == 0 || b == 0 {
+        return (-a..=a)
+            .flat_map(|u| (-b..=b).map(move |v| (u, v)))
+            .collect();
+    }
+    let mut points = Vec::new();
+    let mut plot = |x: i64, y: i64| {
+        let (x, y) = (x as i32, y as i32);
+        points.extend_from_slice(&[(x, y), (-x, y), (x, -y), (-x, -y)]);
+    };
+    // every decision variable is 4 times the one of the textbook algorithm
+    let (a2, b2) = ((a as i64).pow(2), (b as i64).pow(2));
+    let (mut x, mut y) = (0i64, b as i64);
+    let (mut dx, mut dy) = (0, 2 * a2 * y);
+    let mut d = 4 * b2 - 4 * a2 * y + a2;
+    while dx < dy {
+        plot(x, y);
+        x += 1;
+        dx += 2 * b2;
+        if d < 0 {
+            d += 4 * (dx + b2);
+        } else {
+            y -= 1;
+            dy -= 2 * a2;
+            d += 4 * (dx - dy + b2);
+        }
+    }
+    let mut d = b2 * (2 * x + 1).pow(2) + 4 * a2 * (y - 1).pow(2) - 4 * a2 * b2;
+    while y >= 0 {
+        plot(x, y);
+        y -= 1;
+        dy -= 2 * a2;
+        if d > 0 {
+            d += 4 * (a2 - dy);
+        } else {
+            x += 1;
+            dx += 2 * b2;
+            d += 4 * (dx - dy + a2);
+        }
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// the blocks reached from `seeds` through the 26 neighbours, without entering `wall` or
+    /// leaving `space`
+    fn flood(
+        seeds: impl IntoIterator<Item = BlockPos>,
+        wall: &[BlockPos],
+        space: impl Fn(BlockPos) -> bool,
+    ) -> HashSet<BlockPos> {
+        let wall = wall.iter().copied().collect::<HashSet<_>>();
+        let mut reached = HashSet::new();
+        let mut queue = seeds.into_iter().collect::<VecDeque<_>>();
+        while let Some(p) = queue.pop_front() {
+            if !space(p) || wall.contains(&p) || !reached.insert(p) {
+                continue;
+            }
+            queue.extend(neighbors(true).into_iter().map(|o| p + o));
+        }
+        reached
+    }
+
+    #[test]
+    fn hollow_sphere_is_watertight() {
+        for radius in 1..=8 {
+            for thickness in 1..=3 {
+                let shell = hollow_sphere((0, 0, 0), radius, thickness);
+                let inside = sphere((0, 0, 0), radius)
+                    .into_iter()
+                    .filter(|p| !shell.contains(p))
+                    .collect::<Vec<_>>();
+                let r = radius as i32 + 1;
+                let outside = flood(Some(BlockPos::new(r, r, r)), &shell, |p| {
+                    p.x.abs() <= r && p.y.abs() <= r && p.z.abs() <= r
+                });
+                assert!(
+                    inside.iter().all(|p| !outside.contains(p)),
+                    "radius {} thickness {}",
+                    radius,
+                    thickness
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn hollow_cylinder_is_watertight() {
+        let pipes = [
+            ((0, 0, 0), (20, 0, 0), 4),
+            ((0, 0, 0), (0, 15, 0), 3),
+            ((0, 0, 0), (20, 7, -3), 3),
+            ((1, 2, 3), (-9, -4, 12), 5),
+            ((0, 0, 0), (6, 6, 6), 3),
+        ];
+        for &(start, end, radius) in &pipes {
+            let (start, end): (BlockPos, BlockPos) = (start.into(), end.into());
+            let (s, d) = (start.to_vec3(), (end - start).to_vec3());
+            // the pipe is open at its ends, so only the blocks between them are searched
+            let t = |p: BlockPos| (p.to_vec3() - s).dot(d) / d.dot(d);
+            let between = |p: BlockPos| (0.0..=1.0).contains(&t(p));
+            let distance = |p: BlockPos| (p.to_vec3() - s).distance_squared(d * t(p)).sqrt();
+            for thickness in 1..=2 {
+                let wall = hollow_cylinder(start, end, radius, thickness);
+                let (min, max) = (start.min(end), start.max(end));
+                let r = radius as i32 + 3;
+                let region =
+                    crate::region::Region::new(min.offset(-r, -r, -r), max.offset(r, r, r));
+                let seeds = region
+                    .positions()
+                    .filter(|&p| between(p) && distance(p) > radius as f64 + 2.0);
+                let outside = flood(seeds, &wall, |p| region.contains(p) && between(p));
+                let inside = cylinder(start, end, radius)
+                    .into_iter()
+                    .filter(|p| !wall.contains(p))
+                    .collect::<Vec<_>>();
+                assert!(!inside.is_empty());
+                assert!(
+                    inside.iter().all(|p| !outside.contains(p)),
+                    "{:?} {:?} thickness {}",
+                    start,
+                    end,
+                    thickness
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn radius_zero_is_the_center() {
+        let c = BlockPos::new(3, 64, -2);
+        assert_eq!(circle(c, 0, Axis::Y), vec![c]);
+        assert_eq!(disc(c, 0, Axis::X), vec![c]);
+        assert_eq!(sphere(c, 0), vec![c]);
+        assert_eq!(hollow_sphere(c, 0, 1), vec![c]);
+        assert_eq!(torus(c, 0, 0, Axis::Y), vec![c]);
+        // a cylinder of radius 0 is its axis
+        assert_eq!(
+            cylinder(c, c.offset(5, 0, 0), 0),
+            line(c, c.offset(5, 0, 0))
+        );
+        // an ellipse with one radius 0 is a segment
+        assert_eq!(
+            ellipse(c, 0, 2, Axis::Y),
+            line(c.offset(0, 0, -2), c.offset(0, 0, 2))
+        );
+        assert!(pyramid(c, 0, 0).is_empty());
+    }
+
+    #[test]
+    fn thickness_zero_is_empty() {
+        let c = BlockPos::new(0, 64, 0);
+        assert!(ring(c, 3, 0, Axis::Y).is_empty());
+        assert!(hollow_sphere(c, 3, 0).is_empty());
+        assert!(hollow_ellipsoid(c, (4, 2, 3), 0).is_empty());
+        assert!(hollow_cylinder(c, c.offset(3, 0, 0), 2, 0).is_empty());
+        assert!(hollow_pyramid(c, 3, 3, 0).is_empty());
+        assert!(arch(c, 3, 0, Axis::Z, ArchStyle::Round).is_empty());
+    }
+
+    #[test]
+    fn start_equals_end_is_a_disc() {
+        let c = BlockPos::new(0, 64, 0);
+        assert_eq!(line(c, c), vec![c]);
+        assert_eq!(cylinder(c, c, 2), disc(c, 2, Axis::Y));
+        assert_eq!(cone(c, c, 2), disc(c, 2, Axis::Y));
+        // the radius at the end is ignored
+        assert_eq!(frustum(c, c, 1, 5), disc(c, 1, Axis::Y));
+        // every block of a flat pipe touches the outside
+        assert_eq!(hollow_cylinder(c, c, 2, 1), disc(c, 2, Axis::Y));
+    }
+
+    #[test]
+    fn degenerate_polygons() {
+        let (a, b, c) = (
+            BlockPos::new(0, 0, 0),
+            BlockPos::new(2, 0, 0),
+            BlockPos::new(4, 0, 0),
+        );
+        assert!(polygon(&[], &[]).is_empty());
+        assert!(polygon_outline(&[]).is_empty());
+        assert_eq!(polygon(&[a], &[]), vec![a]);
+        assert_eq!(polygon_outline(&[a]), vec![a]);
+        // a collinear polygon has no plane, it is its outline
+        assert_eq!(polygon(&[a, b, c], &[]), line(a, c));
+        assert_eq!(triangle(a, b, c), line(a, c));
+        let diagonal = BlockPos::new(4, 4, 4);
+        assert_eq!(
+            triangle(a, BlockPos::new(2, 2, 2), diagonal),
+            line(a, diagonal)
+        );
+    }
+
+    #[test]
+    fn degenerate_solids_are_empty() {
+        let c = BlockPos::new(0, 64, 0);
+        for sides in 0..3 {
+            assert!(prism(c, 3, sides, 4, Axis::Y).is_empty());
+        }
+        assert!(prism(c, 3, 3, 0, Axis::X).is_empty());
+        assert!(stepped_pyramid(c, 3, 0, 1).is_empty());
+        assert!(stepped_pyramid(c, 3, 1, 0).is_empty());
+        assert!(extrude(vec![c], Axis::X, 0).is_empty());
+        assert_eq!(extrude(vec![c], Axis::X, 1), vec![c]);
+    }
+
+    #[test]
+    fn axis_mapping() {
+        let c = BlockPos::new(0, 0, 0);
+        assert!(circle(c, 2, Axis::X).iter().all(|p| p.x == 0));
+        assert!(circle(c, 2, Axis::Y).iter().all(|p| p.y == 0));
+        assert!(circle(c, 2, Axis::Z).iter().all(|p| p.z == 0));
+        // the radii are along the axes of the plane in x, y, z order
+        for &(normal, u, v) in &[
+            (Axis::X, Axis::Y, Axis::Z),
+            (Axis::Y, Axis::X, Axis::Z),
+            (Axis::Z, Axis::X, Axis::Y),
+        ] {
+            let e = ellipse(c, 5, 2, normal);
+            assert!(e.contains(&c.with(u, 5)) && e.contains(&c.with(v, 2)));
+            assert!(!e.contains(&c.with(u, 2)) && !e.contains(&c.with(v, 5)));
+        }
+        // an arch stands upright, `Axis::Y` is taken as `Axis::Z`
+        let round = arch(c, 3, 1, Axis::X, ArchStyle::Round);
+        assert!(round.iter().all(|p| p.x == 0 && p.y >= 0));
+        assert!(round.contains(&BlockPos::new(0, 0, 4)));
+        assert_eq!(
+            arch(c, 3, 1, Axis::Y, ArchStyle::Pointed),
+            arch(c, 3, 1, Axis::Z, ArchStyle::Pointed)
+        );
+        assert!(prism(c, 2, 4, 5, Axis::Z)
+            .iter()
+            .all(|p| (0..5).contains(&p.z)));
+    }
+
+    #[test]
+    fn points_are_sorted_without_duplicates() {
+        let c = BlockPos::new(1, 64, -1);
+        let shapes = vec![
+            ring(c, 5, 2, Axis::Z),
+            hollow_sphere(c, 5, 2),
+            hollow_frustum(c, c.offset(9, 4, -6), 5, 2, 1),
+            torus(c, 6, 2, Axis::X),
+            hollow_pyramid(c, 5, 4, 1),
+            prism(c, 4, 6, 3, Axis::X),
+            arch(c, 4, 2, Axis::Z, ArchStyle::Pointed),
+            polygon(&[c, c.offset(8, 3, 0), c.offset(0, 6, 5)], &[]),
+        ];
+        for shape in shapes {
+            assert!(shape.windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! The sign module of mcpi-rs
+//! build the signs placed by `world.setSign` (RaspberryJuice only)
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mcpi_rs::prelude::*;
+//!
+//! let mc = Minecraft::connect();
+//!
+//! let sign = Sign::standing(BlockFace::South)
+//!     .line(0, "Tower #3")
+//!     .line(1, "by Steve");
+//! mc.mc_set_sign((10, 64, 10), &sign).unwrap();
+//! ```
+use crate::error::Error;
+use crate::events::BlockFace;
+use crate::items::{Block, SIGN_POST, SIGN_WALL};
+
+/// the number of lines of a sign
+pub const SIGN_LINES: usize = 4;
+/// the number of characters the game shows on a line of a sign
+pub const SIGN_LINE_LENGTH: usize = 15;
+
+/// how a sign is placed
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SignKind {
+    /// a sign on a post, turned in sixteenths of a full turn (0 faces south, 4 faces west)
+    Standing { rotation: u8 },
+    /// a sign on the side of a block, facing north, south, west or east
+    Wall { facing: BlockFace },
+}
+/// a sign with up to four lines of text
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sign {
+    kind: SignKind,
+    lines: [String; SIGN_LINES],
+}
+impl Sign {
+    /// construct a standing sign facing a direction
+    ///
+    /// `Up` and `Down` are treated as south
+    pub fn standing(facing: BlockFace) -> Sign {
+        let rotation = match facing {
+            BlockFace::West => 4,
+            BlockFace::North => 8,
+            BlockFace::East => 12,
+            _ => 0,
+        };
+        Sign::standing_rotation(rotation)
+    }
+    /// construct a standing sign with a rotation (0..=15, 0 faces south, 4 faces west)
+    pub fn standing_rotation(rotation: u8) -> Sign {
+        Sign {
+            kind: SignKind::Standing { rotation },
+            lines: Default::default(),
+        }
+    }
+    /// construct a wall sign facing a direction (the sign hangs on the block behind it)
+    ///
+    /// `Up` and `Down` are treated as south
+    pub fn wall(facing: BlockFace) -> Sign {
+        let facing = match facing {
+            BlockFace::Up | BlockFace::Down => BlockFace::South,
+            f => f,
+        };
+        Sign {
+            kind: SignKind::Wall { facing },
+            lines: Default::default(),
+        }
+    }
+    /// set a line (0..=3) of the sign, lines out of range are ignored
+    pub fn line(mut self, index: usize, text: impl ToString) -> Sign {
+        if let Some(line) = self.lines.get_mut(index) {
+            *line = text.to_string();
+        }
+        self
+    }
+    /// set the lines of the sign from the first one, extra lines are ignored
+    pub fn lines<T: ToString>(mut self, lines: impl IntoIterator<Item = T>) -> Sign {
+        for (line, text) in self.lines.iter_mut().zip(lines) {
+            *line = text.to_string();
+        }
+        self
+    }
+    /// get how the sign is placed
+    pub fn kind(&self) -> SignKind {
+        self.kind
+    }
+    /// get the lines of the sign as they were set
+    pub fn text(&self) -> &[String; SIGN_LINES] {
+        &self.lines
+    }
+    /// get the block of the sign (its data is the rotation or the facing)
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(Sign::wall(BlockFace::East).block(), Block::new(5, SIGN_WALL));
+    /// assert_eq!(Sign::standing(BlockFace::North).block(), Block::new(8, SIGN_POST));
+    /// ```
+    pub fn block(&self) -> Block {
+        match self.kind {
+            SignKind::Standing { rotation } => Block::new(rotation as i32, SIGN_POST),
+            SignKind::Wall { facing } => Block::new(facing.id(), SIGN_WALL),
+        }
+    }
+    /// check the rotation and the length of every line
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert!(Sign::standing(BlockFace::South).line(0, "fifteen chars!!").validate().is_ok());
+    /// assert_eq!(
+    ///     Sign::standing(BlockFace::South).line(2, "sixteen chars!!!").validate(),
+    ///     Err(Error::LineTooLong { line: 2, len: 16, max: SIGN_LINE_LENGTH })
+    /// );
+    /// assert!(Sign::standing_rotation(16).validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        if let SignKind::Standing { rotation } = self.kind {
+            if rotation > 15 {
+                return Err(Error::OutOfRange {
+                    what: String::from("sign rotation"),
+                    value: rotation as i64,
+                    max: 15,
+                });
+            }
+        }
+        for (i, line) in self.escaped_lines().iter().enumerate() {
+            let len = line.chars().count();
+            if len > SIGN_LINE_LENGTH {
+                return Err(Error::LineTooLong {
+                    line: i,
+                    len,
+                    max: SIGN_LINE_LENGTH,
+                });
+            }
+        }
+        Ok(())
+    }
+    /// get the lines as they are sent to the server
+    ///
+    /// the protocol separates arguments by commas and commands by newlines,
+    /// so control characters are removed and commas become `‚` (U+201A, which looks like a comma)
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let sign = Sign::wall(BlockFace::North).line(0, "Hi, you\n");
+    /// assert_eq!(sign.escaped_lines()[0], "Hi\u{201A} you");
+    /// ```
+    pub fn escaped_lines(&self) -> [String; SIGN_LINES] {
+        let mut lines: [String; SIGN_LINES] = Default::default();
+        for (escaped, line) in lines.iter_mut().zip(self.lines.iter()) {
+            *escaped = line
+                .chars()
+                .filter(|c| !c.is_control())
+                .map(|c| if c == ',' { '\u{201A}' } else { c })
+                .collect();
+        }
+        lines
+    }
+}
@@ -0,0 +1,491 @@
+//! The variants module of mcpi-rs
+//! the typed meanings of the data values of blocks
+//!
+//! the constructors build blocks without magic numbers, and the accessors decode the blocks
+//! read with `getBlockWithData` back into the same types
+//!
+//! # Example
+//!
+//! ```
+//! use mcpi_rs::prelude::*;
+//!
+//! assert_eq!(Block::wool(WoolColor::Red), Block::new(14, WOOL));
+//! assert_eq!(Block::stairs(STAIRS_WOOD, Facing::North, false), Block::new(3, STAIRS_WOOD));
+//!
+//! // what `mc_get_block_with_data` replied
+//! let block = Block::decode(String::from("53,6\n"));
+//! assert_eq!(block.stairs_facing(), Some((Facing::South, true)));
+//! ```
+use crate::events::BlockFace;
+use crate::items::*;
+use std::convert::TryFrom;
+
+/// the color of wool, stained clay, stained glass, carpet and concrete
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WoolColor {
+    White,
+    Orange,
+    Magenta,
+    LightBlue,
+    Yellow,
+    Lime,
+    Pink,
+    Gray,
+    LightGray,
+    Cyan,
+    Purple,
+    Blue,
+    Brown,
+    Green,
+    Red,
+    Black,
+}
+impl WoolColor {
+    /// every color, ordered by data value
+    pub const ALL: [WoolColor; 16] = [
+        WoolColor::White,
+        WoolColor::Orange,
+        WoolColor::Magenta,
+        WoolColor::LightBlue,
+        WoolColor::Yellow,
+        WoolColor::Lime,
+        WoolColor::Pink,
+        WoolColor::Gray,
+        WoolColor::LightGray,
+        WoolColor::Cyan,
+        WoolColor::Purple,
+        WoolColor::Blue,
+        WoolColor::Brown,
+        WoolColor::Green,
+        WoolColor::Red,
+        WoolColor::Black,
+    ];
+    /// get the color of a data value
+    pub fn from_data(data: i32) -> Option<WoolColor> {
+        WoolColor::ALL.get(usize::try_from(data).ok()?).copied()
+    }
+    /// get the data value of the color
+    pub fn data(self) -> i32 {
+        self as i32
+    }
+}
+/// the kind of wood of planks, logs, leaves, saplings and wooden slabs
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WoodType {
+    Oak,
+    Spruce,
+    Birch,
+    Jungle,
+    Acacia,
+    DarkOak,
+}
+impl WoodType {
+    /// every kind of wood, ordered by data value
+    pub const ALL: [WoodType; 6] = [
+        WoodType::Oak,
+        WoodType::Spruce,
+        WoodType::Birch,
+        WoodType::Jungle,
+        WoodType::Acacia,
+        WoodType::DarkOak,
+    ];
+    /// get the kind of wood of a data value
+    pub fn from_data(data: i32) -> Option<WoodType> {
+        WoodType::ALL.get(usize::try_from(data).ok()?).copied()
+    }
+    /// get the data value of the kind of wood (of planks, saplings and wooden slabs)
+    pub fn data(self) -> i32 {
+        self as i32
+    }
+}
+/// the kind of stone
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StoneType {
+    Stone,
+    Granite,
+    PolishedGranite,
+    Diorite,
+    PolishedDiorite,
+    Andesite,
+    PolishedAndesite,
+}
+impl StoneType {
+    /// every kind of stone, ordered by data value
+    pub const ALL: [StoneType; 7] = [
+        StoneType::Stone,
+        StoneType::Granite,
+        StoneType::PolishedGranite,
+        StoneType::Diorite,
+        StoneType::PolishedDiorite,
+        StoneType::Andesite,
+        StoneType::PolishedAndesite,
+    ];
+    /// get the kind of stone of a data value
+    pub fn from_data(data: i32) -> Option<StoneType> {
+        StoneType::ALL.get(usize::try_from(data).ok()?).copied()
+    }
+    /// get the data value of the kind of stone
+    pub fn data(self) -> i32 {
+        self as i32
+    }
+}
+/// a horizontal direction, the way stairs and doors face
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Facing {
+    /// -z
+    North,
+    /// +z
+    South,
+    /// -x
+    West,
+    /// +x
+    East,
+}
+impl Facing {
+    /// every direction
+    pub const ALL: [Facing; 4] = [Facing::North, Facing::South, Facing::West, Facing::East];
+    /// get the direction of a side face, `None` for `Up` and `Down`
+    pub fn from_face(face: BlockFace) -> Option<Facing> {
+        match face {
+            BlockFace::North => Some(Facing::North),
+            BlockFace::South => Some(Facing::South),
+            BlockFace::West => Some(Facing::West),
+            BlockFace::East => Some(Facing::East),
+            BlockFace::Up | BlockFace::Down => None,
+        }
+    }
+    /// get the opposite direction
+    pub fn opposite(self) -> Facing {
+        match self {
+            Facing::North => Facing::South,
+            Facing::South => Facing::North,
+            Facing::West => Facing::East,
+            Facing::East => Facing::West,
+        }
+    }
+    /// the direction turned a quarter clockwise (seen from above)
+    pub fn clockwise(self) -> Facing {
+        match self {
+            Facing::North => Facing::East,
+            Facing::East => Facing::South,
+            Facing::South => Facing::West,
+            Facing::West => Facing::North,
+        }
+    }
+    /// the direction as the data value of stairs (0 = east, 1 = west, 2 = south, 3 = north)
+    fn stairs_data(self) -> i32 {
+        match self {
+            Facing::East => 0,
+            Facing::West => 1,
+            Facing::South => 2,
+            Facing::North => 3,
+        }
+    }
+    /// the direction as the data value of doors (0 = east, 1 = south, 2 = west, 3 = north)
+    fn door_data(self) -> i32 {
+        match self {
+            Facing::East => 0,
+            Facing::South => 1,
+            Facing::West => 2,
+            Facing::North => 3,
+        }
+    }
+}
+impl From<Facing> for BlockFace {
+    fn from(facing: Facing) -> BlockFace {
+        match facing {
+            Facing::North => BlockFace::North,
+            Facing::South => BlockFace::South,
+            Facing::West => BlockFace::West,
+            Facing::East => BlockFace::East,
+        }
+    }
+}
+/// the half of the block a slab fills
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SlabHalf {
+    Bottom,
+    Top,
+}
+/// the way a torch points, a torch pointing east hangs on the block west of it
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TorchFacing {
+    East,
+    West,
+    South,
+    North,
+    /// standing on the block below
+    Up,
+}
+impl TorchFacing {
+    /// every direction, ordered by data value (1 = east ... 5 = up)
+    pub const ALL: [TorchFacing; 5] = [
+        TorchFacing::East,
+        TorchFacing::West,
+        TorchFacing::South,
+        TorchFacing::North,
+        TorchFacing::Up,
+    ];
+    /// get the direction of a data value
+    pub fn from_data(data: i32) -> Option<TorchFacing> {
+        TorchFacing::ALL
+            .get(usize::try_from(data - 1).ok()?)
+            .copied()
+    }
+    /// get the data value of the direction
+    pub fn data(self) -> i32 {
+        self as i32 + 1
+    }
+}
+/// the side of the door the hinge is on, seen from the way the door faces
+#[derive(Clone, Copy, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DoorHinge {
+    Left,
+    Right,
+}
+/// a half of a door, a door is two blocks: the lower half and the upper half above it
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DoorHalf {
+    /// the lower half knows where the door faces and whether it is open
+    Lower { facing: Facing, open: bool },
+    /// the upper half knows where the hinge is and whether the door is powered
+    Upper { hinge: DoorHinge, powered: bool },
+}
+impl DoorHalf {
+    /// decode the data value of a door block
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(
+    ///     DoorHalf::from_data(7),
+    ///     Some(DoorHalf::Lower { facing: Facing::North, open: true })
+    /// );
+    /// assert_eq!(
+    ///     DoorHalf::from_data(9),
+    ///     Some(DoorHalf::Upper { hinge: DoorHinge::Right, powered: false })
+    /// );
+    /// ```
+    pub fn from_data(data: i32) -> Option<DoorHalf> {
+        if !(0..16).contains(&data) {
+            return None;
+        }
+        if data & 8 == 0 {
+            let facing =
+                [Facing::East, Facing::South, Facing::West, Facing::North][(data & 3) as usize];
+            Some(DoorHalf::Lower {
+                facing,
+                open: data & 4 != 0,
+            })
+        } else {
+            let hinge = if data & 1 == 0 {
+                DoorHinge::Left
+            } else {
+                DoorHinge::Right
+            };
+            Some(DoorHalf::Upper {
+                hinge,
+                powered: data & 2 != 0,
+            })
+        }
+    }
+    /// get the data value of the half
+    pub fn data(self) -> i32 {
+        match self {
+            DoorHalf::Lower { facing, open } => facing.door_data() | if open { 4 } else { 0 },
+            DoorHalf::Upper { hinge, powered } => {
+                8 | if hinge == DoorHinge::Right { 1 } else { 0 } | if powered { 2 } else { 0 }
+            }
+        }
+    }
+}
+
+/// the blocks colored by [`WoolColor`]
+const COLORED: [Item; 7] = [
+    WOOL,
+    STAINED_GLASS,
+    STAINED_CLAY,
+    STAINED_GLASS_PANE,
+    CARPET,
+    CONCRETE,
+    CONCRETE_POWDER,
+];
+/// the blocks using the data values of stairs
+const STAIRS: [Item; 14] = [
+    STAIRS_WOOD,
+    STAIRS_COBBLESTONE,
+    STAIRS_BRICK,
+    STAIRS_STONE_BRICK,
+    STAIRS_NETHER_BRICK,
+    STAIRS_SANDSTONE,
+    STAIRS_SPRUCE,
+    STAIRS_BIRCH,
+    STAIRS_JUNGLE,
+    STAIRS_QUARTZ,
+    STAIRS_ACACIA,
+    STAIRS_DARK_OAK,
+    STAIRS_RED_SANDSTONE,
+    STAIRS_PURPUR,
+];
+/// the half slabs, the top bit (8) is the upper half
+const SLABS: [Item; 4] = [STONE_SLAB, WOOD_SLAB, STONE_SLAB2, PURPUR_SLAB];
+/// the blocks using the data values of torches
+const TORCHES: [Item; 3] = [TORCH, REDSTONE_TORCH, REDSTONE_TORCH_OFF];
+/// the blocks using the data values of doors
+const DOORS: [Item; 7] = [
+    DOOR_WOOD,
+    DOOR_IRON,
+    DOOR_SPRUCE,
+    DOOR_BIRCH,
+    DOOR_JUNGLE,
+    DOOR_ACACIA,
+    DOOR_DARK_OAK,
+];
+
+impl Block {
+    /// construct a wool block
+    pub fn wool(color: WoolColor) -> Block {
+        Block::new(color.data(), WOOL)
+    }
+    /// construct a colored block (wool, stained glass, stained clay, carpet, concrete...)
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(Block::colored(STAINED_GLASS, WoolColor::Blue), Block::new(11, STAINED_GLASS));
+    /// ```
+    pub fn colored(item: Item, color: WoolColor) -> Block {
+        Block::new(color.data(), item)
+    }
+    /// construct a stone block
+    pub fn stone(kind: StoneType) -> Block {
+        Block::new(kind.data(), STONE)
+    }
+    /// construct a wood planks block
+    pub fn planks(wood: WoodType) -> Block {
+        Block::new(wood.data(), WOOD_PLANKS)
+    }
+    /// construct an upright log, acacia and dark oak logs are [`WOOD2`]
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// assert_eq!(Block::log(WoodType::Birch), Block::new(2, WOOD));
+    /// assert_eq!(Block::log(WoodType::DarkOak), Block::new(1, WOOD2));
+    /// ```
+    pub fn log(wood: WoodType) -> Block {
+        match wood {
+            WoodType::Acacia | WoodType::DarkOak => Block::new(wood.data() - 4, WOOD2),
+            _ => Block::new(wood.data(), WOOD),
+        }
+    }
+    /// construct a stairs block of `item` (such as [`STAIRS_WOOD`]) ascending towards `facing`
+    pub fn stairs(item: Item, facing: Facing, upside_down: bool) -> Block {
+        Block::new(facing.stairs_data() | if upside_down { 4 } else { 0 }, item)
+    }
+    /// construct a half slab of `item` (such as [`STONE_SLAB`]) with the default material
+    pub fn slab(item: Item, half: SlabHalf) -> Block {
+        Block::new(if half == SlabHalf::Top { 8 } else { 0 }, item)
+    }
+    /// construct a wooden half slab
+    pub fn wood_slab(wood: WoodType, half: SlabHalf) -> Block {
+        let top = if half == SlabHalf::Top { 8 } else { 0 };
+        Block::new(wood.data() | top, WOOD_SLAB)
+    }
+    /// construct a torch of `item` (such as [`TORCH`] or [`REDSTONE_TORCH`])
+    pub fn torch(item: Item, facing: TorchFacing) -> Block {
+        Block::new(facing.data(), item)
+    }
+    /// construct a half of a door of `item` (such as [`DOOR_WOOD`])
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let lower = Block::door(DOOR_WOOD, DoorHalf::Lower { facing: Facing::East, open: false });
+    /// let upper = Block::door(DOOR_WOOD, DoorHalf::Upper { hinge: DoorHinge::Left, powered: false });
+    /// assert_eq!((lower.data(), upper.data()), (0, 8));
+    /// ```
+    pub fn door(item: Item, half: DoorHalf) -> Block {
+        Block::new(half.data(), item)
+    }
+
+    /// get the color of a colored block
+    pub fn color(&self) -> Option<WoolColor> {
+        if COLORED.contains(&self.item()) {
+            WoolColor::from_data(self.data())
+        } else {
+            None
+        }
+    }
+    /// get the kind of stone of a stone block
+    pub fn stone_type(&self) -> Option<StoneType> {
+        if self.item() == STONE {
+            StoneType::from_data(self.data())
+        } else {
+            None
+        }
+    }
+    /// get the kind of wood of planks, logs, leaves, saplings and wooden slabs
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// // a birch log lying along x
+    /// assert_eq!(Block::new(6, WOOD).wood_type(), Some(WoodType::Birch));
+    /// assert_eq!(Block::new(13, WOOD2).wood_type(), Some(WoodType::DarkOak));
+    /// assert_eq!(Block::from_item(STONE).wood_type(), None);
+    /// ```
+    pub fn wood_type(&self) -> Option<WoodType> {
+        let data = self.data();
+        match self.item() {
+            WOOD_PLANKS | WOOD_SLAB_DOUBLE => WoodType::from_data(data),
+            SAPLING | WOOD_SLAB => WoodType::from_data(data & 7),
+            WOOD | LEAVES => WoodType::from_data(data & 3),
+            WOOD2 | LEAVES2 => WoodType::from_data(4 + (data & 1)),
+            _ => None,
+        }
+    }
+    /// get the direction and whether a stairs block is upside down
+    pub fn stairs_facing(&self) -> Option<(Facing, bool)> {
+        if !STAIRS.contains(&self.item()) {
+            return None;
+        }
+        let facing = [Facing::East, Facing::West, Facing::South, Facing::North];
+        Some((facing[(self.data() & 3) as usize], self.data() & 4 != 0))
+    }
+    /// get the half of a half slab
+    pub fn slab_half(&self) -> Option<SlabHalf> {
+        if !SLABS.contains(&self.item()) {
+            return None;
+        }
+        Some(if self.data() & 8 == 0 {
+            SlabHalf::Bottom
+        } else {
+            SlabHalf::Top
+        })
+    }
+    /// get the direction of a torch
+    pub fn torch_facing(&self) -> Option<TorchFacing> {
+        if TORCHES.contains(&self.item()) {
+            TorchFacing::from_data(self.data())
+        } else {
+            None
+        }
+    }
+    /// get the half of a door
+    pub fn door_half(&self) -> Option<DoorHalf> {
+        if DOORS.contains(&self.item()) {
+            DoorHalf::from_data(self.data())
+        } else {
+            None
+        }
+    }
+}
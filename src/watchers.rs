@@ -0,0 +1,439 @@
+//! The watchers module of mcpi-rs
+//! synthesize the events the server does not report by polling and diffing
+//!
+//! every watcher is an `EventSource`, so it can be added to an `EventLoop`
+//!
+//! # Example
+//!
+//! ```no_run
+//! use mcpi_rs::prelude::*;
+//! use std::time::Duration;
+//!
+//! let mut el = EventLoop::new(Minecraft::connect());
+//! let mut players = PlayerWatcher::new();
+//! players.set_interval(Duration::from_secs(1));
+//! el.add_source(players);
+//! el.add_source(RegionWatcher::new((0, 60, 0), (63, 100, 63)));
+//! el.on(EventKind::PlayerJoined, EventFilter::new(), |mc, _| {
+//!     mc.mc_post_to_chat("Welcome!");
+//! });
+//! el.on_block_changed(EventFilter::new(), |_, change| {
+//!     println!("{:?} became {:?}", change.pos, change.new);
+//! });
+//! let handle = el.spawn();
+//! ```
+use crate::event_loop::EventSource;
+use crate::events::{BlockChangeEvent, Event, MoveEvent, PlayerEvent};
+use crate::items::Block;
+use crate::minecraft::Minecraft;
+use crate::position::BlockPos;
+use crate::region::Region;
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::{Duration, Instant};
+
+/// synthesize `PlayerJoined`, `PlayerLeft` and `PlayerMovedTile` events
+/// by polling `world.getPlayerIds` and the tiles of the players
+pub struct PlayerWatcher {
+    interval: Duration,
+    threshold: f64,
+    report_existing: bool,
+    last_poll: Option<Instant>,
+    players: Option<BTreeMap<i32, BlockPos>>,
+}
+impl Default for PlayerWatcher {
+    fn default() -> PlayerWatcher {
+        PlayerWatcher::new()
+    }
+}
+impl PlayerWatcher {
+    /// construct a watcher which polls every time it is asked to
+    /// and reports every move to another tile
+    pub fn new() -> PlayerWatcher {
+        PlayerWatcher {
+            interval: Duration::from_secs(0),
+            threshold: 1.0,
+            report_existing: false,
+            last_poll: None,
+            players: None,
+        }
+    }
+    /// set the minimum time between two polls of the server
+    ///
+    /// polls within the interval return no events
+    pub fn set_interval(&mut self, interval: Duration) -> &mut PlayerWatcher {
+        self.interval = interval;
+        self
+    }
+    /// set how far (in blocks) a player has to move from the last reported tile
+    /// before another move is reported
+    pub fn set_threshold(&mut self, threshold: f64) -> &mut PlayerWatcher {
+        self.threshold = threshold;
+        self
+    }
+    /// whether the players online at the first poll are reported as joined (default `false`)
+    pub fn set_report_existing(&mut self, report: bool) -> &mut PlayerWatcher {
+        self.report_existing = report;
+        self
+    }
+    /// get the players known to the watcher and their last reported tiles
+    pub fn players(&self) -> Vec<(i32, BlockPos)> {
+        self.players
+            .iter()
+            .flatten()
+            .map(|(id, pos)| (*id, *pos))
+            .collect()
+    }
+    /// diff the players online now against the known ones and return the events
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mut w = PlayerWatcher::new();
+    /// w.set_threshold(2.0);
+    /// // the first update only takes a snapshot
+    /// assert!(w.update(vec![(1, BlockPos::new(0, 64, 0))]).is_empty());
+    ///
+    /// let events = w.update(vec![(1, BlockPos::new(1, 64, 0)), (2, BlockPos::new(5, 70, 5))]);
+    /// assert_eq!(
+    ///     events,
+    ///     vec![Event::PlayerJoined(PlayerEvent { entity_id: 2, pos: BlockPos::new(5, 70, 5) })]
+    /// );
+    ///
+    /// let events = w.update(vec![(1, BlockPos::new(2, 64, 0))]);
+    /// assert_eq!(events.len(), 2);
+    /// assert_eq!(events[0].kind(), EventKind::PlayerLeft);
+    /// assert_eq!(
+    ///     events[1],
+    ///     Event::PlayerMovedTile(MoveEvent {
+    ///         entity_id: 1,
+    ///         from: BlockPos::new(0, 64, 0),
+    ///         to: BlockPos::new(2, 64, 0)
+    ///     })
+    /// );
+    /// ```
+    pub fn update(&mut self, online: Vec<(i32, BlockPos)>) -> Vec<Event> {
+        let now = online.into_iter().collect::<BTreeMap<i32, BlockPos>>();
+        let mut events = Vec::new();
+        let known = match self.players.take() {
+            Some(known) => known,
+            None => {
+                if self.report_existing {
+                    events.extend(now.iter().map(|(id, pos)| {
+                        Event::PlayerJoined(PlayerEvent {
+                            entity_id: *id,
+                            pos: *pos,
+                        })
+                    }));
+                }
+                self.players = Some(now);
+                return events;
+            }
+        };
+        let mut players = BTreeMap::new();
+        for (id, pos) in known.iter() {
+            if !now.contains_key(id) {
+                events.push(Event::PlayerLeft(PlayerEvent {
+                    entity_id: *id,
+                    pos: *pos,
+                }));
+            }
+        }
+        for (id, pos) in now.into_iter() {
+            match known.get(&id) {
+                None => {
+                    events.push(Event::PlayerJoined(PlayerEvent { entity_id: id, pos }));
+                    players.insert(id, pos);
+                }
+                Some(last) => {
+                    if pos != *last && pos.distance(*last) >= self.threshold {
+                        events.push(Event::PlayerMovedTile(MoveEvent {
+                            entity_id: id,
+                            from: *last,
+                            to: pos,
+                        }));
+                        players.insert(id, pos);
+                    } else {
+                        players.insert(id, *last);
+                    }
+                }
+            }
+        }
+        self.players = Some(players);
+        events
+    }
+    /// poll the server (if the interval has elapsed) and return the events
+    pub fn poll(&mut self, mc: Minecraft) -> Vec<Event> {
+        let now = Instant::now();
+        if let Some(last) = self.last_poll {
+            if now.duration_since(last) < self.interval {
+                return Vec::new();
+            }
+        }
+        self.last_poll = Some(now);
+        let online = mc
+            .clone()
+            .mc_get_player_entity_ids()
+            .into_iter()
+            .filter_map(|id| Some((id, mc.clone().try_get_pos_entity_int(id)?)))
+            .collect();
+        self.update(online)
+    }
+}
+impl EventSource for PlayerWatcher {
+    fn poll(&mut self, mc: &Minecraft) -> Vec<Event> {
+        PlayerWatcher::poll(self, mc.clone())
+    }
+}
+/// the default edge length of the sub-cuboids scanned by `RegionWatcher`
+pub const DEFAULT_CHUNK_SIZE: i32 = 16;
+/// how far (in blocks) from a player `RegionWatcher` expects changes
+const PLAYER_REACH: i32 = 6;
+
+/// synthesize `BlockChanged` events by snapshotting a cuboid with `world.getBlocks`
+/// and diffing it against later scans
+///
+/// the cuboid is split into sub-cuboids (16x16x16 by default) which are scanned separately.
+/// each poll rescans the dirty sub-cuboids and a few (`set_budget`) of the others in turn,
+/// so the cost of a poll stays bounded however large the cuboid is.
+/// a sub-cuboid is dirty if it changed in the last scan, if a player is near it
+/// or if it was marked by `mark_dirty`
+///
+/// the server only reports block ids, so changes of the data value alone are not detected
+pub struct RegionWatcher {
+    region: Region,
+    chunk_size: i32,
+    budget: usize,
+    track_players: bool,
+    interval: Duration,
+    last_poll: Option<Instant>,
+    snapshots: BTreeMap<BlockPos, Vec<Block>>,
+    dirty: BTreeSet<BlockPos>,
+    cursor: usize,
+}
+impl RegionWatcher {
+    /// construct a watcher of the cuboid between two corners (inclusive)
+    pub fn new(corner1: impl Into<BlockPos>, corner2: impl Into<BlockPos>) -> RegionWatcher {
+        RegionWatcher::from_region(Region::new(corner1, corner2))
+    }
+    /// construct a watcher of a region
+    pub fn from_region(region: Region) -> RegionWatcher {
+        RegionWatcher {
+            region,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            budget: 1,
+            track_players: true,
+            interval: Duration::from_secs(0),
+            last_poll: None,
+            snapshots: BTreeMap::new(),
+            dirty: BTreeSet::new(),
+            cursor: 0,
+        }
+    }
+    /// get the watched region
+    pub fn region(&self) -> Region {
+        self.region
+    }
+    /// set the edge length of the sub-cuboids (this drops the snapshot)
+    pub fn set_chunk_size(&mut self, size: i32) -> &mut RegionWatcher {
+        self.chunk_size = size.max(1);
+        self.snapshots.clear();
+        self.dirty.clear();
+        self.cursor = 0;
+        self
+    }
+    /// set how many clean sub-cuboids are rescanned each poll
+    pub fn set_budget(&mut self, budget: usize) -> &mut RegionWatcher {
+        self.budget = budget;
+        self
+    }
+    /// whether the sub-cuboids near the players are rescanned each poll (default `true`)
+    pub fn set_track_players(&mut self, track: bool) -> &mut RegionWatcher {
+        self.track_players = track;
+        self
+    }
+    /// set the minimum time between two polls of the server
+    ///
+    /// polls within the interval return no events
+    pub fn set_interval(&mut self, interval: Duration) -> &mut RegionWatcher {
+        self.interval = interval;
+        self
+    }
+    /// get the sub-cuboids in scanning order
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let w = RegionWatcher::new((0, 0, 0), (19, 9, 9));
+    /// assert_eq!(
+    ///     w.sub_cuboids(),
+    ///     vec![Region::new((0, 0, 0), (15, 9, 9)), Region::new((16, 0, 0), (19, 9, 9))]
+    /// );
+    /// ```
+    pub fn sub_cuboids(&self) -> Vec<Region> {
+        self.region.chunks(self.chunk_size)
+    }
+    /// get the min corner of the sub-cuboid containing a position
+    fn chunk_of(&self, pos: BlockPos) -> Option<BlockPos> {
+        if !self.region.contains(pos) {
+            return None;
+        }
+        let (min, size) = (self.region.min(), self.chunk_size);
+        Some(BlockPos::new(
+            min.x + (pos.x - min.x) / size * size,
+            min.y + (pos.y - min.y) / size * size,
+            min.z + (pos.z - min.z) / size * size,
+        ))
+    }
+    /// make the next poll rescan the sub-cuboid containing a position
+    pub fn mark_dirty(&mut self, pos: impl Into<BlockPos>) -> &mut RegionWatcher {
+        if let Some(chunk) = self.chunk_of(pos.into()) {
+            self.dirty.insert(chunk);
+        }
+        self
+    }
+    /// make the next poll rescan every sub-cuboid touching the cuboid between two corners
+    pub fn mark_dirty_cuboid(
+        &mut self,
+        corner1: impl Into<BlockPos>,
+        corner2: impl Into<BlockPos>,
+    ) -> &mut RegionWatcher {
+        self.mark_dirty_region(&Region::new(corner1, corner2))
+    }
+    /// make the next poll rescan every sub-cuboid touching a region
+    pub fn mark_dirty_region(&mut self, region: &Region) -> &mut RegionWatcher {
+        let chunks = self
+            .sub_cuboids()
+            .into_iter()
+            .filter(|c| c.intersects(region))
+            .map(|c| c.min())
+            .collect::<Vec<_>>();
+        self.dirty.extend(chunks);
+        self
+    }
+    /// get the snapshot of a position (`None` if it has not been scanned yet)
+    pub fn block_at(&self, pos: impl Into<BlockPos>) -> Option<Block> {
+        let pos = pos.into();
+        let chunk = self.chunk_of(pos)?;
+        let blocks = self.snapshots.get(&chunk)?;
+        let index = self.bounds(chunk).positions().position(|p| p == pos)?;
+        blocks.get(index).cloned()
+    }
+    fn bounds(&self, chunk: BlockPos) -> Region {
+        let size = self.chunk_size;
+        Region::new(
+            chunk,
+            chunk
+                .offset(size - 1, size - 1, size - 1)
+                .min(self.region.max()),
+        )
+    }
+    /// diff the result of a bulk read of a sub-cuboid against the snapshot
+    /// and return the events (the first scan of a sub-cuboid only takes a snapshot)
+    ///
+    /// `blocks` must be ordered like `world.getBlocks`; a scan with more or fewer blocks than
+    /// the sub-cuboid (a short read) is discarded and the sub-cuboid stays dirty
+    /// # Example
+    /// ```
+    /// use mcpi_rs::prelude::*;
+    ///
+    /// let mut w = RegionWatcher::new((0, 0, 0), (1, 0, 1));
+    /// let air = Block::from_item(AIR);
+    /// let stone = Block::from_item(STONE);
+    /// assert!(w.apply_scan((0, 0, 0), vec![air.clone(); 4]).is_empty());
+    ///
+    /// let events = w.apply_scan((0, 0, 0), vec![air.clone(), air.clone(), stone.clone(), air.clone()]);
+    /// assert_eq!(
+    ///     events,
+    ///     vec![Event::BlockChanged(BlockChangeEvent { pos: BlockPos::new(1, 0, 0), old: air.clone(), new: stone.clone() })]
+    /// );
+    /// assert_eq!(w.block_at((1, 0, 0)), Some(stone.clone()));
+    ///
+    /// // a short read changes nothing
+    /// assert!(w.apply_scan((0, 0, 0), vec![air.clone(); 3]).is_empty());
+    /// assert_eq!(w.block_at((1, 0, 0)), Some(stone));
+    /// ```
+    pub fn apply_scan(&mut self, chunk: impl Into<BlockPos>, blocks: Vec<Block>) -> Vec<Event> {
+        let chunk = chunk.into();
+        let bounds = self.bounds(chunk);
+        if blocks.len() as u64 != bounds.volume() {
+            self.dirty.insert(chunk);
+            return Vec::new();
+        }
+        let mut events = Vec::new();
+        if let Some(old) = self.snapshots.get(&chunk) {
+            for ((pos, old), new) in bounds.positions().zip(old.iter()).zip(blocks.iter()) {
+                if old != new {
+                    events.push(Event::BlockChanged(BlockChangeEvent {
+                        pos,
+                        old: old.clone(),
+                        new: new.clone(),
+                    }));
+                }
+            }
+        }
+        if !events.is_empty() {
+            self.dirty.insert(chunk);
+        }
+        self.snapshots.insert(chunk, blocks);
+        events
+    }
+    fn scan(&mut self, mc: &Minecraft, chunk: BlockPos) -> Vec<Event> {
+        let blocks = mc.clone().mc_get_region(self.bounds(chunk));
+        self.apply_scan(chunk, blocks)
+    }
+    /// poll the server (if the interval has elapsed) and return the events
+    ///
+    /// the first poll scans the whole cuboid to take the snapshot
+    pub fn poll(&mut self, mc: Minecraft) -> Vec<Event> {
+        let now = Instant::now();
+        if let Some(last) = self.last_poll {
+            if now.duration_since(last) < self.interval {
+                return Vec::new();
+            }
+        }
+        self.last_poll = Some(now);
+        let chunks = self
+            .sub_cuboids()
+            .into_iter()
+            .map(|c| c.min())
+            .collect::<Vec<_>>();
+        let mut events = Vec::new();
+        if self.snapshots.len() < chunks.len() {
+            for chunk in chunks.iter() {
+                if !self.snapshots.contains_key(chunk) {
+                    events.extend(self.scan(&mc, *chunk));
+                }
+            }
+            return events;
+        }
+        if self.track_players {
+            for id in mc.clone().mc_get_player_entity_ids() {
+                if let Some(p) = mc.clone().try_get_pos_entity_int(id) {
+                    self.mark_dirty_region(&Region::block(p).expand(PLAYER_REACH));
+                }
+            }
+        }
+        let dirty = std::mem::take(&mut self.dirty);
+        for chunk in dirty.iter() {
+            events.extend(self.scan(&mc, *chunk));
+        }
+        let mut scanned = 0;
+        for _ in 0..chunks.len() {
+            if scanned >= self.budget {
+                break;
+            }
+            let chunk = chunks[self.cursor % chunks.len()];
+            self.cursor = (self.cursor + 1) % chunks.len();
+            if !dirty.contains(&chunk) {
+                events.extend(self.scan(&mc, chunk));
+                scanned += 1;
+            }
+        }
+        events
+    }
+}
+impl EventSource for RegionWatcher {
+    fn poll(&mut self, mc: &Minecraft) -> Vec<Event> {
+        RegionWatcher::poll(self, mc.clone())
+    }
+}